@@ -0,0 +1,199 @@
+//! Cellular automata: cells that are alive or dead, all changing at once each
+//! generation depending on how many of their neighbours are alive.
+
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use crate::{Point, SparseGrid};
+
+/// Which cells there are and which of them neighbour each other. Being a
+/// neighbour has to go both ways.
+pub trait Topology<C: Point> {
+    fn neighbours(&self, cell: C) -> Vec<C>;
+
+    /// Cells to look at every generation, even when none of their neighbours
+    /// are alive. Any other cell only comes alive next to a live one.
+    fn cells(&self) -> Vec<C>;
+}
+
+/// The unbounded lattice of a [`Point`] type, where cells neighbour the points
+/// [`Point::neighbours`] says they do.
+#[derive(Clone, Copy, Debug)]
+pub struct Lattice;
+
+impl<P: Point> Topology<P> for Lattice {
+    fn neighbours(&self, cell: P) -> Vec<P> {
+        cell.neighbours()
+    }
+
+    /// None: a lattice has no end, so cells can't come alive on their own.
+    fn cells(&self) -> Vec<P> {
+        vec![]
+    }
+}
+
+/// A fixed set of cells, each with its own list of neighbours, such as the
+/// seats of a map and the seats visible from each.
+#[derive(Clone, Debug)]
+pub struct Bounded<C: Point> {
+    neighbours: HashMap<C, Vec<C>>,
+}
+
+impl<C: Point> Bounded<C> {
+    /// The cells with their neighbours.
+    pub fn new(neighbours: impl IntoIterator<Item = (C, Vec<C>)>) -> Bounded<C> {
+        Bounded {
+            neighbours: neighbours.into_iter().collect(),
+        }
+    }
+}
+
+impl<C: Point> Topology<C> for Bounded<C> {
+    fn neighbours(&self, cell: C) -> Vec<C> {
+        self.neighbours.get(&cell).cloned().unwrap_or_default()
+    }
+
+    fn cells(&self) -> Vec<C> {
+        self.neighbours.keys().copied().collect()
+    }
+}
+
+/// A dead cell comes alive when the number of its live neighbours is within
+/// `birth`, a live one stays alive when it's within `survival`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub birth: RangeInclusive<usize>,
+    pub survival: RangeInclusive<usize>,
+}
+
+impl Rule {
+    /// Whether a cell is alive next generation.
+    pub fn alive(&self, alive: bool, live_neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(&live_neighbours)
+        } else {
+            self.birth.contains(&live_neighbours)
+        }
+    }
+}
+
+/// The live cells of a [`Topology`], changing by a [`Rule`].
+#[derive(Clone, Debug)]
+pub struct Automaton<C: Point, T: Topology<C>> {
+    topology: T,
+    rule: Rule,
+    live: SparseGrid<C>,
+    generation: usize,
+}
+
+impl<C: Point, T: Topology<C>> Automaton<C, T> {
+    pub fn new(topology: T, rule: Rule, live: SparseGrid<C>) -> Automaton<C, T> {
+        Automaton {
+            topology,
+            rule,
+            live,
+            generation: 0,
+        }
+    }
+
+    /// The cells alive in the current generation.
+    pub fn live(&self) -> &SparseGrid<C> {
+        &self.live
+    }
+
+    /// How many generations have gone by.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Moves on to the next generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut live_neighbours: HashMap<C, usize> = self
+            .topology
+            .cells()
+            .into_iter()
+            .chain(self.live.points())
+            .map(|cell| (cell, 0))
+            .collect();
+
+        for cell in self.live.points() {
+            for neighbour in self.topology.neighbours(cell) {
+                *live_neighbours.entry(neighbour).or_default() += 1;
+            }
+        }
+
+        let next: SparseGrid<C> = live_neighbours
+            .into_iter()
+            .filter(|(cell, count)| self.rule.alive(self.live.contains(*cell), *count))
+            .map(|(cell, _)| cell)
+            .collect();
+
+        let changed = next != self.live;
+        self.live = next;
+        self.generation += 1;
+        changed
+    }
+
+    /// Moves `generations` generations ahead.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Moves on until a generation is the same as the one before, and returns
+    /// how many generations that took. Never returns for automata that go
+    /// round in circles instead.
+    pub fn run_until_stable(&mut self) -> usize {
+        let start = self.generation;
+        while self.step() {}
+        self.generation - start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life() -> Rule {
+        Rule {
+            birth: 3..=3,
+            survival: 2..=3,
+        }
+    }
+
+    #[test]
+    fn test_blinker() {
+        let blinker: SparseGrid<[isize; 2]> = vec![[0, 1], [1, 1], [2, 1]].into_iter().collect();
+        let mut automaton = Automaton::new(Lattice, life(), blinker.clone());
+
+        assert!(automaton.step());
+        let mut live: Vec<[isize; 2]> = automaton.live().points().collect();
+        live.sort_unstable();
+        assert_eq!(live, vec![[1, 0], [1, 1], [1, 2]]);
+
+        automaton.run(3);
+        assert_eq!(automaton.generation(), 4);
+        assert_eq!(automaton.live(), &blinker);
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        // A line of three cells that come alive when nothing around them is
+        // and die when both their neighbours are, like the seats of day 11
+        let topology = Bounded::new(vec![
+            ([0, 0], vec![[1, 0]]),
+            ([1, 0], vec![[0, 0], [2, 0]]),
+            ([2, 0], vec![[1, 0]]),
+        ]);
+        let rule = Rule {
+            birth: 0..=0,
+            survival: 0..=1,
+        };
+        let mut automaton = Automaton::new(topology, rule, SparseGrid::new());
+
+        assert_eq!(automaton.run_until_stable(), 3);
+        let mut live: Vec<[isize; 2]> = automaton.live().points().collect();
+        live.sort_unstable();
+        assert_eq!(live, vec![[0, 0], [2, 0]]);
+    }
+}
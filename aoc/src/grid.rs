@@ -0,0 +1,566 @@
+//! Two dimensional grids of cells: [`Grid`] for rectangles where every cell
+//! matters, like most character maps in the puzzles, and [`SparseGrid`] for
+//! unbounded spaces where only a few cells are set.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    hash::Hash,
+    iter::FromIterator,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Line, ParseError};
+
+/// Offsets to the cells above, left, right and below a cell.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to the eight cells around a cell, diagonals included, which are
+/// also the eight directions to look in from it.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangle of cells, stored row after row. Cells are addressed by `(x, y)`,
+/// the column counting from the left and the row counting from the top.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid made of `rows`, which all have to be as long as each other.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows have to be the same length"
+        );
+
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a character map into a grid, see [`parse_grid`].
+    pub fn parse<'a>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        expected: &str,
+        cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Ok(Grid::from_rows(parse_grid(lines, expected, cell)?))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` past the edges. Coordinates are signed
+    /// so stepping off the grid from a cell on its edge is no special case.
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.index_of(x, y).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.index_of(x, y).map(move |index| &mut self.cells[index])
+    }
+
+    /// The cell at `(x, y)` of the grid repeated endlessly in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize);
+        let y = y.rem_euclid(self.height as isize);
+        &self[(x as usize, y as usize)]
+    }
+
+    fn index_of(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            Some(y as usize * self.width + x as usize)
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` doesn't take 0, and a grid 0 cells wide has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Every cell, row after row.
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Every cell's coordinates, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    /// Every cell with its coordinates, row after row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Coordinates of the cells above, left, right and below `(x, y)` that
+    /// are on the grid.
+    pub fn neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Coordinates of the cells around `(x, y)`, diagonals included, that are
+    /// on the grid.
+    pub fn neighbours_8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            self.index_of(x, y).map(|_| (x as usize, y as usize))
+        })
+    }
+
+    /// A grid of the same size, with `cell` applied to each cell.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with `cell` everywhere.
+    pub fn new(width: usize, height: usize, cell: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    fn from_fn(width: usize, height: usize, cell: impl Fn(usize, usize) -> T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: (0..width * height)
+                .map(|index| cell(index % width, index / width))
+                .collect(),
+        }
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotated(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flipped_horizontally(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// The grid mirrored top to bottom.
+    pub fn flipped_vertically(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    /// The 8 ways to turn and flip the grid, starting with the grid as it is.
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut orientations = vec![];
+        let mut grid = self.clone();
+
+        for _ in 0..4 {
+            let flipped = grid.flipped_horizontally();
+            orientations.push(grid);
+            orientations.push(flipped);
+            grid = orientations[orientations.len() - 2].rotated();
+        }
+
+        orientations
+    }
+
+    /// The `width` by `height` part of the grid with `(x, y)` as its top left cell.
+    pub fn subgrid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<T> {
+        assert!(x + width <= self.width && y + height <= self.height);
+        Grid::from_fn(width, height, |dx, dy| self[(x + dx, y + dy)].clone())
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is off the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// One line per row, the way the puzzles draw their maps.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Coordinates in a [`SparseGrid`], in any number of dimensions or even on a
+/// hexagonal grid.
+pub trait Point: Copy + Eq + Hash {
+    /// Every point touching this one.
+    fn neighbours(self) -> Vec<Self>;
+}
+
+/// Points in `N` dimensions touch the `3^N - 1` points around them, diagonals
+/// included.
+impl<const N: usize> Point for [isize; N] {
+    fn neighbours(self) -> Vec<[isize; N]> {
+        let mut neighbours = vec![self];
+
+        for axis in 0..N {
+            neighbours = neighbours
+                .into_iter()
+                .flat_map(|point| {
+                    (-1..=1).map(move |delta| {
+                        let mut neighbour = point;
+                        neighbour[axis] += delta;
+                        neighbour
+                    })
+                })
+                .collect();
+        }
+
+        neighbours.retain(|neighbour| *neighbour != self);
+        neighbours
+    }
+}
+
+/// Cells on an unbounded grid, keeping only the ones that are set. Without a
+/// cell type it's a set of points.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseGrid<P: Point, T = ()> {
+    cells: HashMap<P, T>,
+}
+
+impl<P: Point, T> SparseGrid<P, T> {
+    pub fn new() -> SparseGrid<P, T> {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Sets the cell at `point`, returning what it was before.
+    pub fn insert(&mut self, point: P, cell: T) -> Option<T> {
+        self.cells.insert(point, cell)
+    }
+
+    pub fn remove(&mut self, point: P) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    pub fn get(&self, point: P) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn contains(&self, point: P) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// How many cells are set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The points of every cell that is set, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = P> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Every cell that is set with its point, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// How many of the points touching `point` are set.
+    pub fn count_neighbours(&self, point: P) -> usize {
+        point
+            .neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+            .count()
+    }
+}
+
+impl<P: Point> SparseGrid<P> {
+    /// Sets `point` if it isn't, unsets it otherwise. Returns whether it's set now.
+    pub fn toggle(&mut self, point: P) -> bool {
+        if self.cells.remove(&point).is_none() {
+            self.cells.insert(point, ());
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<T> SparseGrid<[isize; 2], T> {
+    /// The smallest rectangle holding every cell that is set, as its top left
+    /// and bottom right corners, or `None` if none are.
+    pub fn bounds(&self) -> Option<([isize; 2], [isize; 2])> {
+        let mut points = self.points();
+        let first = points.next()?;
+
+        Some(points.fold((first, first), |(min, max), [x, y]| {
+            (
+                [min[0].min(x), min[1].min(y)],
+                [max[0].max(x), max[1].max(y)],
+            )
+        }))
+    }
+
+    /// The part of the grid within [`SparseGrid::bounds`] as a dense grid,
+    /// turning each cell into one of `U` with `cell`.
+    pub fn to_grid<U>(&self, mut cell: impl FnMut(Option<&T>) -> U) -> Grid<U> {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return Grid::from_rows(vec![]),
+        };
+
+        Grid::from_rows(
+            (min[1]..=max[1])
+                .map(|y| (min[0]..=max[0]).map(|x| cell(self.get([x, y]))).collect())
+                .collect(),
+        )
+    }
+}
+
+impl<P: Point, T> Default for SparseGrid<P, T> {
+    fn default() -> SparseGrid<P, T> {
+        SparseGrid::new()
+    }
+}
+
+impl<P: Point> FromIterator<P> for SparseGrid<P> {
+    fn from_iter<I: IntoIterator<Item = P>>(points: I) -> SparseGrid<P> {
+        SparseGrid {
+            cells: points.into_iter().map(|point| (point, ())).collect(),
+        }
+    }
+}
+
+/// Parses a character map (one row per line) into rows of cells, converting
+/// each character with `cell`. Characters `cell` doesn't know about are
+/// reported as errors, described by `expected`, and so are rows whose length
+/// doesn't match the first one.
+pub fn parse_grid<'a, T>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    expected: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = vec![];
+
+    for line in lines {
+        let row = line.parse(|text| {
+            text.chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| {
+                        ParseError::unexpected(expected, c.to_string()).at_column(i + 1)
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()
+        })?;
+
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::unexpected(
+                    format!("a row of {} cells", first.len()),
+                    line.text,
+                )
+                .on_line(line.number));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+
+    fn tree(c: char) -> Option<bool> {
+        match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let input = Input::from("#.\n.#");
+        let grid = parse_grid(input.numbered_lines(), "`#` or `.`", tree).unwrap();
+        assert_eq!(grid, vec![vec![true, false], vec![false, true]]);
+    }
+
+    fn sample() -> Grid<char> {
+        let input = Input::from("ab\ncd\nef");
+        Grid::parse(input.numbered_lines(), "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn test_grid_access() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'b');
+        assert_eq!(grid.get(0, 2), Some(&'e'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get_wrapping(-1, 4), &'d');
+        assert_eq!(grid.row(1), &['c', 'd']);
+        assert_eq!(grid.column(1).collect::<String>(), "bdf");
+    }
+
+    #[test]
+    fn test_grid_neighbours() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours_4(0, 1).collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1), (0, 2)]
+        );
+        assert_eq!(grid.neighbours_8(0, 0).count(), 3);
+        assert_eq!(grid.neighbours_8(1, 1).count(), 5);
+    }
+
+    #[test]
+    fn test_grid_transformations() {
+        let grid = sample();
+        assert_eq!(grid.to_string(), "ab\ncd\nef\n");
+        assert_eq!(grid.rotated().to_string(), "eca\nfdb\n");
+        assert_eq!(grid.flipped_horizontally().to_string(), "ba\ndc\nfe\n");
+        assert_eq!(grid.flipped_vertically().to_string(), "ef\ncd\nab\n");
+        assert_eq!(grid.subgrid(1, 1, 1, 2).to_string(), "d\nf\n");
+
+        let orientations = grid.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert!(orientations.contains(&grid.flipped_vertically()));
+        assert!(orientations.contains(&grid.rotated().rotated().rotated()));
+        assert_eq!(grid.rotated().rotated().rotated().rotated(), grid);
+    }
+
+    #[test]
+    fn test_point_neighbours() {
+        let mut neighbours = [2, 3, 4].neighbours();
+        neighbours.sort_unstable();
+
+        let mut expected = vec![];
+        for x in 1..=3 {
+            for y in 2..=4 {
+                for z in 3..=5 {
+                    if [x, y, z] != [2, 3, 4] {
+                        expected.push([x, y, z]);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(neighbours, expected);
+        assert_eq!([0; 4].neighbours().len(), 80);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<[isize; 2]> = vec![[0, 0], [1, 0], [-1, 1]].into_iter().collect();
+        assert!(!grid.toggle([1, 0]));
+        assert!(grid.toggle([1, 1]));
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.count_neighbours([0, 1]), 3);
+        assert_eq!(grid.bounds(), Some(([-1, 0], [1, 1])));
+        assert_eq!(
+            grid.to_grid(|cell| if cell.is_some() { '#' } else { '.' })
+                .to_string(),
+            ".#.\n#.#\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_grid_errors() {
+        let input = Input::from("#.\n.?");
+        let error = parse_grid(input.numbered_lines(), "`#` or `.`", tree).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let input = Input::from("#.\n.#.");
+        let error = parse_grid(input.numbered_lines(), "`#` or `.`", tree).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a row of 2 cells, found `.#.` at line 2, column 1"
+        );
+    }
+}
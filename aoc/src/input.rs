@@ -0,0 +1,209 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::Lines,
+};
+
+use crate::Line;
+
+/// Where to read a puzzle input from, as given on the command line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl InputSource {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.into())
+        }
+    }
+
+    /// Like [`InputSource::from_arg`], but an argument that isn't an existing file
+    /// is taken as the puzzle input itself. Meant for days whose whole input fits
+    /// in a command line, like `2,15,0,9,1,20`.
+    pub fn from_arg_or_text(arg: &str) -> InputSource {
+        match InputSource::from_arg(arg) {
+            InputSource::File(path) if !path.is_file() => InputSource::Text(arg.into()),
+            source => source,
+        }
+    }
+
+    pub fn read(&self) -> io::Result<Input> {
+        match self {
+            InputSource::File(path) => Input::from_file(path),
+            InputSource::Stdin => Input::from_stdin(),
+            InputSource::Text(text) => Ok(Input::new(text.as_str())),
+        }
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Text(_) => write!(f, "the given text"),
+        }
+    }
+}
+
+/// A puzzle input loaded into memory, regardless of where it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    pub fn new(text: impl Into<String>) -> Input {
+        Input { text: text.into() }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Input> {
+        fs::read_to_string(path).map(Input::new)
+    }
+
+    pub fn from_stdin() -> io::Result<Input> {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(Input::new(text))
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Lines along with their line numbers, for parsers that report errors.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.lines().enumerate().map(|(i, text)| Line {
+            number: i + 1,
+            text,
+        })
+    }
+
+    /// Groups of numbered lines separated by blank lines.
+    pub fn numbered_groups(&self) -> Groups<impl Iterator<Item = Line<'_>>> {
+        groups(self.numbered_lines())
+    }
+
+    /// The line right after the last one, where errors about missing input go.
+    pub fn end(&self) -> Line<'_> {
+        Line {
+            number: self.lines().count() + 1,
+            text: "",
+        }
+    }
+
+    /// Groups of lines separated by blank lines, see [`groups`].
+    pub fn groups(&self) -> Groups<Lines<'_>> {
+        groups(self.lines())
+    }
+}
+
+impl From<&str> for Input {
+    fn from(text: &str) -> Input {
+        Input::new(text)
+    }
+}
+
+impl From<String> for Input {
+    fn from(text: String) -> Input {
+        Input::new(text)
+    }
+}
+
+/// Splits `lines` into groups separated by one or more blank lines. Blank lines
+/// are never part of a group, so there are no empty groups either.
+pub fn groups<I>(lines: I) -> Groups<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Groups {
+        lines: lines.into_iter(),
+    }
+}
+
+pub struct Groups<I> {
+    lines: I,
+}
+
+impl<I> Iterator for Groups<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = vec![];
+
+        for line in self.lines.by_ref() {
+            if !line.as_ref().trim().is_empty() {
+                group.push(line);
+            } else if !group.is_empty() {
+                break;
+            }
+        }
+
+        if group.is_empty() {
+            None
+        } else {
+            Some(group)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_groups() {
+        let input = Input::from("a\nb\n\nc\n\n\nd\ne\n");
+
+        assert_eq!(
+            input.groups().collect::<Vec<_>>(),
+            vec![vec!["a", "b"], vec!["c"], vec!["d", "e"]]
+        );
+    }
+
+    #[test]
+    fn test_groups_ignore_surrounding_blank_lines() {
+        let groups: Vec<Vec<&str>> = groups(vec!["", "a", "", "  ", "b", ""]).collect();
+        assert_eq!(groups, vec![vec!["a"], vec!["b"]]);
+    }
+
+    #[test]
+    fn test_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("input.txt"),
+            InputSource::File("input.txt".into())
+        );
+        assert_eq!(
+            InputSource::from_arg_or_text("2,15,0,9,1,20"),
+            InputSource::Text("2,15,0,9,1,20".into())
+        );
+        assert_eq!(
+            InputSource::from_arg_or_text("Cargo.toml").read().unwrap(),
+            Input::from_file("Cargo.toml").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lines_handle_crlf() {
+        let input = Input::from("a\r\nb\r\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+}
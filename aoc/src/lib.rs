@@ -0,0 +1,22 @@
+//! Helpers shared by every day of Advent of Code 2020: loading the puzzle input,
+//! splitting it into blank-line separated groups, parsing character maps into
+//! grids, running cellular automata on them, drawing simulations step by step,
+//! tracing what solvers do, reporting parse errors and the [`Solution`] trait
+//! every day implements.
+
+pub mod automaton;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod solution;
+pub mod trace;
+pub mod visualize;
+
+pub use automaton::{Automaton, Rule};
+pub use grid::{parse_grid, Grid, Point, SparseGrid};
+pub use input::{groups, Groups, Input, InputSource};
+pub use parse::{Line, ParseError, ParseErrorKind};
+pub use solution::{
+    main, main_with_text_input, print_answers, solve, solve_timed, Answer, Part, Solution, Timings,
+};
+pub use visualize::{Canvas, Color, Frame, Visualize};
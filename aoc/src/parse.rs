@@ -0,0 +1,215 @@
+use std::{error::Error, fmt, ops::Deref, str::FromStr};
+
+use crate::Input;
+
+/// What went wrong while parsing a puzzle input, and where. Lines and columns
+/// are 1-based, columns count characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Found `found` where `expected` should have been.
+    Unexpected { expected: String, found: String },
+    /// The line, or the whole input, ended before `expected`.
+    Missing { expected: String },
+}
+
+impl ParseError {
+    /// An error at the start of its line. Use [`ParseError::at_column`] and
+    /// [`ParseError::on_line`] to move it where it belongs.
+    pub fn unexpected(expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError {
+            line: 1,
+            column: 1,
+            kind: ParseErrorKind::Unexpected {
+                expected: expected.into(),
+                found: found.into(),
+            },
+        }
+    }
+
+    pub fn missing(expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: 1,
+            column: 1,
+            kind: ParseErrorKind::Missing {
+                expected: expected.into(),
+            },
+        }
+    }
+
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        self.column = column;
+        self
+    }
+
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        self.line = line;
+        self
+    }
+
+    /// Shifts the error right by `columns`, for errors found while parsing a
+    /// fragment that doesn't start at the beginning of its line.
+    pub fn offset(mut self, columns: usize) -> ParseError {
+        self.column += columns;
+        self
+    }
+
+    /// Renders the error along with the offending line of `input`, pointing at
+    /// the column where it happened.
+    pub fn diagnostic(&self, input: &Input) -> String {
+        let mut diagnostic = self.to_string();
+
+        if let Some(line) = input.lines().nth(self.line - 1) {
+            let gutter = " ".repeat(self.line.to_string().len());
+            let width = match &self.kind {
+                ParseErrorKind::Unexpected { found, .. } => found.chars().count().max(1),
+                ParseErrorKind::Missing { .. } => 1,
+            };
+
+            diagnostic.push_str(&format!("\n{} |\n{} | {}\n", gutter, self.line, line));
+            diagnostic.push_str(&format!(
+                "{} | {}{}",
+                gutter,
+                " ".repeat(self.column - 1),
+                "^".repeat(width)
+            ));
+        }
+
+        diagnostic
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Unexpected { expected, found } if found.is_empty() => {
+                write!(f, "expected {}, found nothing", expected)?
+            }
+            ParseErrorKind::Unexpected { expected, found } => {
+                write!(f, "expected {}, found `{}`", expected, found)?
+            }
+            ParseErrorKind::Missing { expected } => write!(f, "missing {}", expected)?,
+        }
+
+        write!(f, " at line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A line of the input along with its 1-based line number, so errors found
+/// while parsing it can say where they happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Parses the line's text with `parser`, placing any error on this line.
+    pub fn parse<T>(
+        &self,
+        parser: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parser(self.text).map_err(|error| error.on_line(self.number))
+    }
+
+    /// An error placed right after the end of this line.
+    pub fn missing(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::missing(expected)
+            .on_line(self.number)
+            .at_column(self.text.chars().count() + 1)
+    }
+}
+
+impl Deref for Line<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+impl AsRef<str> for Line<'_> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+/// 1-based column of the character starting at `byte` in `text`.
+pub fn column(text: &str, byte: usize) -> usize {
+    text[..byte].chars().count() + 1
+}
+
+/// Parses `text`, found at `column` of its line, as a number.
+pub fn number<T: FromStr>(text: &str, column: usize) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::unexpected("a number", text).at_column(column))
+}
+
+/// Whitespace separated words of `text`, along with the column each one starts at.
+pub fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split(' ')
+        .scan(0, |byte, word| {
+            let start = *byte;
+            *byte += word.len() + 1;
+            Some((start, word))
+        })
+        .filter(|(_, word)| !word.is_empty())
+        .map(move |(start, word)| (column(text, start), word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::unexpected("a number", "abc")
+            .at_column(5)
+            .on_line(2);
+        assert_eq!(
+            error.to_string(),
+            "expected a number, found `abc` at line 2, column 5"
+        );
+
+        let error = ParseError::missing("`)`").at_column(3).on_line(1);
+        assert_eq!(error.to_string(), "missing `)` at line 1, column 3");
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = Input::from("1\n2\nthree\n");
+        let error = number::<usize>("three", 1).unwrap_err().on_line(3);
+
+        assert_eq!(
+            error.diagnostic(&input),
+            "expected a number, found `three` at line 3, column 1\n  |\n3 | three\n  | ^^^^^"
+        );
+    }
+
+    #[test]
+    fn test_line_parse() {
+        let line = Line {
+            number: 7,
+            text: "12 x",
+        };
+        let error = line
+            .parse(|text| number::<usize>(&text[3..], 4))
+            .unwrap_err();
+
+        assert_eq!((error.line, error.column), (7, 4));
+    }
+
+    #[test]
+    fn test_words() {
+        let words: Vec<(usize, &str)> = words("ab  cd e").collect();
+        assert_eq!(words, vec![(1, "ab"), (5, "cd"), (8, "e")]);
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    convert::TryFrom,
+    env, fmt, process,
+    time::{Duration, Instant},
+};
+
+use crate::{trace, Input, InputSource, ParseError};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The answer to a puzzle part. Most of them are numbers, but some days ask
+/// for a string, and day 25 has no second part at all.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(i64::try_from(n).expect("Answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.into())
+    }
+}
+
+/// A day's puzzle: parsing the input once, then answering each part from it.
+pub trait Solution: Sized {
+    /// Day of the advent calendar, from 1 to 25.
+    const DAY: u8;
+
+    fn parse(input: &Input) -> Result<Self, ParseError>;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+/// Parses `input` and solves each of `parts`, in order.
+pub fn solve<S: Solution>(input: &Input, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+    let solution = parse::<S>(input)?;
+    Ok(parts
+        .iter()
+        .map(|part| solve_part(&solution, *part))
+        .collect())
+}
+
+/// Parses `input`, with trace events saying so.
+fn parse<S: Solution>(input: &Input) -> Result<S, ParseError> {
+    trace::in_context(S::DAY, None, || S::parse(input))
+}
+
+/// Solves `part`, with trace events saying which part they are about.
+fn solve_part<S: Solution>(solution: &S, part: Part) -> Answer {
+    trace::in_context(S::DAY, Some(part), || solution.solve(part))
+}
+
+/// Answers to some parts of a day, along with how long parsing and each part took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub parts: Vec<(Part, Answer, Duration)>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .map(|(_, _, elapsed)| *elapsed)
+                .sum::<Duration>()
+    }
+}
+
+/// Same as [`solve`], but timing parsing and each part separately.
+pub fn solve_timed<S: Solution>(input: &Input, parts: &[Part]) -> Result<Timings, ParseError> {
+    let start = Instant::now();
+    let solution = parse::<S>(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = solve_part(&solution, *part);
+            (*part, answer, start.elapsed())
+        })
+        .collect();
+
+    Ok(Timings { parse, parts })
+}
+
+/// Prints the answers to both parts the way every day's binary does.
+pub fn print_answers<S: Solution>(solution: &S) {
+    for part in &Part::ALL {
+        match solve_part(solution, *part) {
+            Answer::None => {}
+            answer => println!("Part {}: {}", part, answer),
+        }
+    }
+}
+
+/// Entry point for every day's binary. The only argument is the puzzle input:
+/// a path, or `-` to read it from stdin. Defaults to `./input.txt`.
+pub fn main<S: Solution>() {
+    let source = env::args().nth(1).map_or_else(
+        || InputSource::from_arg("./input.txt"),
+        |arg| InputSource::from_arg(&arg),
+    );
+
+    run_main::<S>(source);
+}
+
+/// Same as [`main`], but the input can also be given inline, as one or more
+/// arguments, for days whose whole input is a short list of numbers.
+pub fn main_with_text_input<S: Solution>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let source = match args.as_slice() {
+        [] => InputSource::from_arg("./input.txt"),
+        [arg] => InputSource::from_arg_or_text(arg),
+        args => InputSource::Text(args.join(" ")),
+    };
+
+    run_main::<S>(source);
+}
+
+fn run_main<S: Solution>(source: InputSource) {
+    let input = source.read().unwrap_or_else(|error| {
+        eprintln!("Unable to read {}: {}", source, error);
+        process::exit(1);
+    });
+
+    match parse::<S>(&input) {
+        Ok(solution) => print_answers(&solution),
+        Err(error) => {
+            eprintln!("Unable to parse {}: {}", source, error.diagnostic(&input));
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-3_isize).to_string(), "-3");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
@@ -0,0 +1,198 @@
+//! Trace events: what a solver works out on its way to an answer, one line per
+//! event, for when an answer is wrong and the question is where.
+//!
+//! Solvers record events with the [`trace!`](crate::trace!) macro, which does
+//! nothing until a [`Tracer`] is installed. Events are tagged with the day and
+//! part being solved, which [`solve`](crate::solve) and friends keep track of,
+//! and come out like
+//!
+//! ```text
+//! day=16 part=2 event=field_found field="departure location" position=3
+//! ```
+
+use std::{
+    cell::Cell,
+    fmt::{self, Write as _},
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Mutex,
+    },
+};
+
+use crate::Part;
+
+/// Records an event named by the first argument, with `key = value` fields
+/// formatted with `Display`. Fields are only formatted if the event is traced.
+///
+/// ```
+/// # let round = 1;
+/// aoc::trace!("round_started", round = round, players = "both");
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($event:expr $(, $key:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::record(
+                $event,
+                &[$((stringify!($key), &$value as &dyn ::std::fmt::Display)),*],
+            );
+        }
+    };
+}
+
+/// One bit per day that is traced, none while there is no tracer.
+static DAYS: AtomicU32 = AtomicU32::new(0);
+
+static TRACER: Mutex<Option<Tracer>> = Mutex::new(None);
+
+thread_local! {
+    /// The day and part being solved on this thread. The part is `None`
+    /// while parsing.
+    static CONTEXT: Cell<Option<(u8, Option<Part>)>> = const { Cell::new(None) };
+}
+
+/// Where trace events go.
+pub struct Tracer {
+    out: Box<dyn Write + Send>,
+    days: u32,
+    error: Option<io::Error>,
+}
+
+impl Tracer {
+    /// Writes the events of every day to `out`.
+    pub fn new(out: impl Write + Send + 'static) -> Tracer {
+        Tracer {
+            out: Box::new(out),
+            days: !0,
+            error: None,
+        }
+    }
+
+    /// Only the events of `days`, or of every day if it's empty.
+    pub fn days(mut self, days: &[u8]) -> Tracer {
+        if !days.is_empty() {
+            self.days = days.iter().fold(0, |bits, day| bits | 1 << day);
+        }
+        self
+    }
+
+    /// Starts tracing to this tracer, replacing any other.
+    pub fn install(self) {
+        let days = self.days;
+        *TRACER.lock().unwrap() = Some(self);
+        DAYS.store(days, Ordering::Relaxed);
+    }
+}
+
+/// Stops tracing, flushing the events. Returns the first error writing them.
+pub fn finish() -> io::Result<()> {
+    DAYS.store(0, Ordering::Relaxed);
+
+    match TRACER.lock().unwrap().take() {
+        Some(mut tracer) => match tracer.error.take() {
+            Some(error) => Err(error),
+            None => tracer.out.flush(),
+        },
+        None => Ok(()),
+    }
+}
+
+/// Runs `f` as solving `part` of `day`, or parsing its input if `part` is
+/// `None`, so the events it records say so.
+pub fn in_context<T>(day: u8, part: Option<Part>, f: impl FnOnce() -> T) -> T {
+    let previous = CONTEXT.with(|context| context.replace(Some((day, part))));
+    let result = f();
+    CONTEXT.with(|context| context.set(previous));
+    result
+}
+
+/// Whether events recorded now are traced.
+pub fn enabled() -> bool {
+    let days = DAYS.load(Ordering::Relaxed);
+    days != 0
+        && CONTEXT.with(|context| match context.get() {
+            Some((day, _)) => days & 1 << day != 0,
+            None => false,
+        })
+}
+
+/// Writes an event, see [`trace!`](crate::trace!).
+pub fn record(event: &str, fields: &[(&str, &dyn fmt::Display)]) {
+    let (day, part) = match CONTEXT.with(Cell::get) {
+        Some(context) => context,
+        None => return,
+    };
+
+    let mut line = format!("day={} part=", day);
+    match part {
+        Some(part) => write!(line, "{}", part).unwrap(),
+        None => line.push_str("parse"),
+    }
+    write!(line, " event={}", value(event)).unwrap();
+    for (key, field) in fields {
+        write!(line, " {}={}", key, value(&field.to_string())).unwrap();
+    }
+    line.push('\n');
+
+    if let Some(tracer) = TRACER.lock().unwrap().as_mut() {
+        if tracer.error.is_none() {
+            tracer.error = tracer.out.write_all(line.as_bytes()).err();
+        }
+    }
+}
+
+/// Quotes values that would otherwise run into the next field.
+fn value(text: &str) -> String {
+    if text.is_empty() || text.contains(|c: char| c.is_whitespace() || c == '"' || c == '=') {
+        format!("{:?}", text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// A buffer the test can still read after handing it to the tracer.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(bytes)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // The tracer is global, so everything touching it is in this one test
+    #[test]
+    fn test_trace() {
+        trace!("before_install");
+
+        let buffer = Buffer::default();
+        Tracer::new(buffer.clone()).days(&[16]).install();
+
+        trace!("outside_any_day");
+        in_context(16, None, || trace!("parsed", fields = 20));
+        in_context(16, Some(Part::Two), || {
+            trace!("field_found", field = "departure location", position = 3)
+        });
+        in_context(7, Some(Part::One), || trace!("other_day"));
+        finish().unwrap();
+
+        in_context(16, Some(Part::One), || trace!("after_finish"));
+
+        assert_eq!(
+            String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap(),
+            "day=16 part=parse event=parsed fields=20\n\
+             day=16 part=2 event=field_found field=\"departure location\" position=3\n"
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! Drawing the simulation days step by step: each step becomes a [`Frame`],
+//! which a [`Canvas`] shows in the terminal or saves as an image.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+use crate::{trace, Grid, Input, ParseError, Part, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GREY: Color = Color::rgb(96, 96, 96);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(133, 153, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+
+    /// Whether the color is closer to black than to white, which is all a PBM
+    /// image can tell apart.
+    pub fn is_dark(self) -> bool {
+        // Weights for how bright each channel looks, from ITU-R BT.601
+        let brightness = 299 * self.r as u32 + 587 * self.g as u32 + 114 * self.b as u32;
+        brightness < 128 * 1000
+    }
+}
+
+/// One step of a simulation, one pixel per cell.
+pub type Frame = Grid<Color>;
+
+/// Somewhere to show frames, one after another.
+pub trait Canvas {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// A day that can draw how it gets to its answers.
+pub trait Visualize: Solution {
+    /// The parts [`Visualize::visualize`] can draw.
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    /// Draws every step of solving `part` on `canvas`.
+    fn visualize(&self, part: Part, canvas: &mut dyn Canvas) -> io::Result<()>;
+}
+
+/// Parses `input` and draws `part` being solved. Parse errors come out first,
+/// errors drawing inside.
+pub fn visualize<S: Visualize>(
+    input: &Input,
+    part: Part,
+    canvas: &mut dyn Canvas,
+) -> Result<io::Result<()>, ParseError> {
+    let solution = trace::in_context(S::DAY, None, || S::parse(input))?;
+    Ok(trace::in_context(S::DAY, Some(part), || {
+        solution.visualize(part, canvas)
+    }))
+}
+
+/// Animates frames in a terminal with ANSI escape codes. Each character holds
+/// two pixels, one above the other, so cells come out about square.
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+    step: bool,
+}
+
+impl<W: Write> Terminal<W> {
+    /// Shows a frame every `delay`.
+    pub fn new(out: W, delay: Duration) -> Terminal<W> {
+        Terminal {
+            out,
+            delay,
+            step: false,
+        }
+    }
+
+    /// Waits for Enter on stdin after each frame instead.
+    pub fn stepping(out: W) -> Terminal<W> {
+        Terminal {
+            out,
+            delay: Duration::ZERO,
+            step: true,
+        }
+    }
+}
+
+impl<W: Write> Canvas for Terminal<W> {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        self.out.write_all(ansi(frame).as_bytes())?;
+        self.out.flush()?;
+
+        if self.step {
+            io::stdin().lock().read_line(&mut String::new())?;
+        } else {
+            thread::sleep(self.delay);
+        }
+
+        Ok(())
+    }
+}
+
+/// The frame as text, clearing the screen first.
+fn ansi(frame: &Frame) -> String {
+    let mut text = String::from("\x1b[2J\x1b[H");
+
+    for y in (0..frame.height()).step_by(2) {
+        for x in 0..frame.width() {
+            let top = frame[(x, y)];
+            let bottom = frame
+                .get(x as isize, y as isize + 1)
+                .copied()
+                .unwrap_or(Color::BLACK);
+
+            // An upper half block, in the top pixel's color over the bottom one's
+            write!(
+                text,
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                top.r, top.g, top.b, bottom.r, bottom.g, bottom.b
+            )
+            .unwrap();
+        }
+        text.push_str("\x1b[0m\n");
+    }
+
+    text
+}
+
+/// The image formats [`Images`] can write.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ImageFormat {
+    /// Black and white
+    Pbm,
+    /// Full color
+    Ppm,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Pbm => "pbm",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+
+    pub fn write(self, frame: &Frame, out: &mut impl Write) -> io::Result<()> {
+        match self {
+            ImageFormat::Pbm => write_pbm(frame, out),
+            ImageFormat::Ppm => write_ppm(frame, out),
+        }
+    }
+}
+
+/// Saves each frame as an image in a directory, numbered in the order they
+/// are drawn: `<prefix>-0000.ppm`, `<prefix>-0001.ppm` and so on.
+pub struct Images {
+    dir: PathBuf,
+    prefix: String,
+    format: ImageFormat,
+    frames: usize,
+}
+
+impl Images {
+    /// Creates `dir` if it isn't there yet.
+    pub fn new(
+        dir: impl Into<PathBuf>,
+        prefix: impl Into<String>,
+        format: ImageFormat,
+    ) -> io::Result<Images> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Images {
+            dir,
+            prefix: prefix.into(),
+            format,
+            frames: 0,
+        })
+    }
+
+    /// How many frames were saved so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+}
+
+impl Canvas for Images {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self.dir.join(format!(
+            "{}-{:04}.{}",
+            self.prefix,
+            self.frames,
+            self.format.extension()
+        ));
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        self.format.write(frame, &mut file)?;
+        file.flush()?;
+
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+/// Plain PBM: a 1 for each dark pixel and a 0 for each light one.
+pub fn write_pbm(frame: &Frame, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "P1")?;
+    writeln!(out, "{} {}", frame.width(), frame.height())?;
+
+    for row in frame.rows() {
+        let bits: Vec<&str> = row
+            .iter()
+            .map(|color| if color.is_dark() { "1" } else { "0" })
+            .collect();
+        writeln!(out, "{}", bits.join(" "))?;
+    }
+
+    Ok(())
+}
+
+/// Raw PPM: three bytes per pixel, row after row.
+pub fn write_ppm(frame: &Frame, out: &mut impl Write) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", frame.width(), frame.height())?;
+
+    for color in frame.cells() {
+        out.write_all(&[color.r, color.g, color.b])?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkers() -> Frame {
+        Grid::from_rows(vec![
+            vec![Color::BLACK, Color::WHITE],
+            vec![Color::RED, Color::BLACK],
+        ])
+    }
+
+    #[test]
+    fn test_is_dark() {
+        assert!(Color::BLACK.is_dark());
+        assert!(Color::GREY.is_dark());
+        assert!(!Color::WHITE.is_dark());
+        assert!(!Color::GREEN.is_dark());
+    }
+
+    #[test]
+    fn test_write_pbm() {
+        let mut out = vec![];
+        write_pbm(&checkers(), &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "P1\n2 2\n1 0\n1 1\n");
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let mut out = vec![];
+        write_ppm(&checkers(), &mut out).unwrap();
+
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend_from_slice(&[0, 0, 0, 255, 255, 255, 220, 50, 47, 0, 0, 0]);
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_terminal() {
+        let mut out = vec![];
+        Terminal::new(&mut out, Duration::ZERO)
+            .draw(&checkers())
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[H\
+             \x1b[38;2;0;0;0m\x1b[48;2;220;50;47m\u{2580}\
+             \x1b[38;2;255;255;255m\x1b[48;2;0;0;0m\u{2580}\
+             \x1b[0m\n"
+        );
+    }
+}
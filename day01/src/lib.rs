@@ -0,0 +1,300 @@
+use std::cmp::Ordering;
+
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day01 {
+    numbers: Vec<i64>,
+}
+
+impl Day01 {
+    /// The entries of the expense report, in order.
+    pub fn numbers(&self) -> &[i64] {
+        &self.numbers
+    }
+
+    fn product_of_entries(&self, k: usize) -> Answer {
+        let entries = k_sum(&self.numbers, k, TARGET, Solutions::First)
+            .pop()
+            .expect("Did not find solution");
+
+        trace!("entries_found", entries = sum(&entries));
+        product(&entries)
+            .expect("Product of the entries does not fit in an i64")
+            .into()
+    }
+}
+
+/// What the entries have to add up to in the puzzle.
+pub const TARGET: i64 = 2020;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    fn parse(input: &Input) -> Result<Day01, ParseError> {
+        let numbers = input
+            .numbered_lines()
+            .map(|line| line.parse(|text| number(text, 1)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day01 { numbers })
+    }
+
+    fn part1(&self) -> Answer {
+        self.product_of_entries(2)
+    }
+
+    fn part2(&self) -> Answer {
+        self.product_of_entries(3)
+    }
+}
+
+/// How many solutions [`k_sum`] looks for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Solutions {
+    /// Stops at the first one
+    First,
+    /// Every distinct one
+    All,
+}
+
+/// Ways to pick `k` entries of `numbers` that add up to `target`, each as the
+/// entries from smallest to largest. Entries are picked at most once, but the
+/// same value can be picked as many times as it's in `numbers`. Solutions with
+/// the same values only come up once.
+///
+/// Sorts the entries, then goes through the smallest entry of each solution
+/// and so on, and finds the last two from both ends of the rest at once.
+pub fn k_sum(numbers: &[i64], k: usize, target: i64, solutions: Solutions) -> Vec<Vec<i64>> {
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+
+    let mut found = vec![];
+    let mut picked = Vec::with_capacity(k);
+    find_sums(
+        &sorted,
+        k,
+        i128::from(target),
+        solutions,
+        &mut picked,
+        &mut found,
+    );
+
+    found
+}
+
+/// Adds the ways to pick `k` more of `sorted` adding up to `target` to
+/// `found`, after the entries already `picked`. Sums are `i128`s so they can't
+/// overflow on the way.
+fn find_sums(
+    sorted: &[i64],
+    k: usize,
+    target: i128,
+    solutions: Solutions,
+    picked: &mut Vec<i64>,
+    found: &mut Vec<Vec<i64>>,
+) {
+    let done = |found: &Vec<Vec<i64>>| solutions == Solutions::First && !found.is_empty();
+    let solution = |picked: &[i64], last: &[i64]| [picked, last].concat();
+
+    if sorted.len() < k {
+        return;
+    }
+
+    match k {
+        0 => {
+            if target == 0 {
+                found.push(picked.clone());
+            }
+        }
+        1 => {
+            if let Ok(i) = sorted.binary_search_by_key(&target, |n| i128::from(*n)) {
+                found.push(solution(picked, &[sorted[i]]));
+            }
+        }
+        2 => {
+            let (mut i, mut j) = (0, sorted.len() - 1);
+
+            while i < j && !done(found) {
+                let (a, b) = (sorted[i], sorted[j]);
+
+                match (i128::from(a) + i128::from(b)).cmp(&target) {
+                    Ordering::Less => i += 1,
+                    Ordering::Greater => j -= 1,
+                    Ordering::Equal => {
+                        found.push(solution(picked, &[a, b]));
+
+                        // Skip past both values, the same pair again isn't a new solution
+                        while i < j && sorted[i] == a {
+                            i += 1;
+                        }
+                        while i < j && sorted[j] == b {
+                            j -= 1;
+                        }
+                    }
+                }
+            }
+        }
+        _ => {
+            for i in 0..=sorted.len() - k {
+                if done(found) {
+                    break;
+                }
+                if i > 0 && sorted[i] == sorted[i - 1] {
+                    continue;
+                }
+
+                // The smallest sum from here on only grows
+                let smallest: i128 = sorted[i..i + k].iter().map(|n| i128::from(*n)).sum();
+                if smallest > target {
+                    break;
+                }
+
+                picked.push(sorted[i]);
+                find_sums(
+                    &sorted[i + 1..],
+                    k - 1,
+                    target - i128::from(sorted[i]),
+                    solutions,
+                    picked,
+                    found,
+                );
+                picked.pop();
+            }
+        }
+    }
+}
+
+/// The product of `entries`, unless it doesn't fit in an `i64`.
+pub fn product(entries: &[i64]) -> Option<i64> {
+    entries
+        .iter()
+        .try_fold(1_i64, |product, entry| product.checked_mul(*entry))
+}
+
+/// Every way to pick entries adding up to a target, summed up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    /// Every distinct solution, see [`k_sum`]
+    pub solutions: Vec<Vec<i64>>,
+    /// The solution with the largest product, and that product
+    pub largest: Option<(Vec<i64>, i64)>,
+    /// The solution with the smallest product, and that product
+    pub smallest: Option<(Vec<i64>, i64)>,
+    /// How many solutions have a product that doesn't fit in an `i64`, and
+    /// aren't in the running for largest or smallest
+    pub overflowing: usize,
+}
+
+/// Finds every way to pick `k` entries of `numbers` adding up to `target`,
+/// and which of them have the largest and smallest product.
+pub fn report(numbers: &[i64], k: usize, target: i64) -> Report {
+    let solutions = k_sum(numbers, k, target, Solutions::All);
+    let products: Vec<(&Vec<i64>, i64)> = solutions
+        .iter()
+        .filter_map(|entries| Some((entries, product(entries)?)))
+        .collect();
+
+    let entry = |(entries, product): &(&Vec<i64>, i64)| ((*entries).clone(), *product);
+    let largest = products
+        .iter()
+        .max_by_key(|(_, product)| *product)
+        .map(entry);
+    let smallest = products
+        .iter()
+        .min_by_key(|(_, product)| *product)
+        .map(entry);
+
+    Report {
+        overflowing: solutions.len() - products.len(),
+        solutions,
+        largest,
+        smallest,
+    }
+}
+
+/// Entries as a sum, e.g. `1721 + 299`.
+pub fn sum(entries: &[i64]) -> String {
+    entries
+        .iter()
+        .map(|entry| entry.to_string())
+        .collect::<Vec<String>>()
+        .join(" + ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day01 {
+        Day01::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(514579));
+        assert_eq!(day.part2(), Answer::from(241861950));
+    }
+
+    #[test]
+    fn test_entries_count_once() {
+        let day = parse_sample("1010\n1000\n1020\n500\n510\n");
+        assert_eq!(day.part1(), Answer::from(1000 * 1020));
+        assert_eq!(day.part2(), Answer::from(1010 * 500 * 510));
+    }
+
+    #[test]
+    fn test_duplicated_entries() {
+        let day = parse_sample("1010\n3\n1010\n");
+        assert_eq!(day.part1(), Answer::from(1010 * 1010));
+    }
+
+    #[test]
+    fn test_large_entries() {
+        let day = parse_sample("3000000000\n-2999997980\n7\n");
+        assert_eq!(day.part1(), Answer::from(-8999993940000000000_i64));
+    }
+
+    #[test]
+    fn test_report() {
+        let numbers = [1, 2, 2, 3, 4, 5, -1, i64::MAX, i64::MIN + 7];
+
+        let report = report(&numbers, 3, 7);
+        assert_eq!(report.solutions.len(), 4);
+        assert_eq!(report.largest, Some((vec![2, 2, 3], 12)));
+        assert_eq!(report.smallest, Some((vec![-1, 3, 5], -15)));
+        assert_eq!(report.overflowing, 1);
+
+        assert_eq!(product(&[i64::MAX, 1]), Some(i64::MAX));
+        assert_eq!(product(&[i64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn test_k_sum() {
+        let numbers = [1, 2, 2, 3, 4, 5, -1];
+
+        assert_eq!(
+            k_sum(&numbers, 3, 7, Solutions::All),
+            vec![vec![-1, 3, 5], vec![1, 2, 4], vec![2, 2, 3],]
+        );
+        assert_eq!(
+            k_sum(&numbers, 3, 7, Solutions::First),
+            vec![vec![-1, 3, 5]]
+        );
+        assert_eq!(
+            k_sum(&numbers, 2, 4, Solutions::All),
+            vec![vec![-1, 5], vec![1, 3], vec![2, 2]]
+        );
+        assert_eq!(
+            k_sum(&numbers, 4, 6, Solutions::All),
+            vec![vec![-1, 1, 2, 4], vec![-1, 2, 2, 3]]
+        );
+        assert_eq!(k_sum(&numbers, 1, 4, Solutions::All), vec![vec![4]]);
+        assert_eq!(
+            k_sum(&numbers, 0, 0, Solutions::All),
+            vec![Vec::<i64>::new()]
+        );
+        assert!(k_sum(&numbers, 8, 16, Solutions::All).is_empty());
+        assert!(k_sum(&[2020], 2, 4040, Solutions::All).is_empty());
+    }
+}
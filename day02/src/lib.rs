@@ -0,0 +1,474 @@
+use std::{collections::HashSet, fmt};
+
+use aoc::{
+    parse::{column, number},
+    trace, Answer, Input, ParseError, Solution,
+};
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct Day02 {
+    passwords: Vec<Password>,
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    fn parse(input: &Input) -> Result<Day02, ParseError> {
+        let regex = Regex::new(r"^(\d+)-(\d+) ([a-z]): (.+)$").expect("Failed to compile regex");
+        let passwords = input
+            .numbered_lines()
+            .map(|line| {
+                line.parse(|text| {
+                    let captures = regex.captures(text).ok_or_else(|| {
+                        ParseError::unexpected("a policy and password like `1-3 a: abcde`", text)
+                    })?;
+
+                    let bound = |i| {
+                        let bound = captures.get(i).unwrap();
+                        number(bound.as_str(), column(text, bound.start()))
+                    };
+                    let first_index = bound(1)?;
+                    let second_index = bound(2)?;
+                    let letter: char = captures[3].chars().next().unwrap();
+                    let password = String::from(&captures[4]);
+
+                    Ok(Password::new(
+                        line.number,
+                        password,
+                        first_index,
+                        second_index,
+                        letter,
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day02 { passwords })
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_valid(&LetterCount).into()
+    }
+
+    // For the actual policies described in part 2
+    fn part2(&self) -> Answer {
+        self.count_valid(&LetterPositions).into()
+    }
+}
+
+impl Day02 {
+    /// How many passwords of the database pass `policy`.
+    pub fn count_valid(&self, policy: &dyn PasswordPolicy) -> usize {
+        self.passwords
+            .iter()
+            .filter(|password| password.check(policy).violation.is_none())
+            .count()
+    }
+
+    /// Checks every password of the database against each of `policies`, in
+    /// the order of the database.
+    pub fn check_all<'a>(&'a self, policies: &'a [Box<dyn PasswordPolicy>]) -> Vec<Check<'a>> {
+        self.passwords
+            .iter()
+            .flat_map(|password| {
+                policies
+                    .iter()
+                    .map(move |policy| password.check(policy.as_ref()))
+            })
+            .collect()
+    }
+
+    /// How many passwords there are in the database.
+    pub fn len(&self) -> usize {
+        self.passwords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passwords.is_empty()
+    }
+}
+
+/// How a password of the database fares under a policy.
+pub struct Check<'a> {
+    /// Line of the database the password is on
+    pub line: usize,
+    pub rule: &'a Rule,
+    pub password: &'a str,
+    pub policy: &'a dyn PasswordPolicy,
+    /// Why the password doesn't pass, `None` if it does
+    pub violation: Option<String>,
+}
+
+/// What the numbers and letter before a password say, like `1-3 a`. What they
+/// mean is up to the [`PasswordPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub letter: char,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}", self.first, self.second, self.letter)
+    }
+}
+
+/// A way to tell good passwords from bad ones. Shows as the name
+/// [`policy`] looks it up by.
+pub trait PasswordPolicy: fmt::Display {
+    /// Checks `password`, given the rule it comes with in the database. Says
+    /// why if it doesn't pass.
+    fn check(&self, rule: &Rule, password: &str) -> Result<(), String>;
+}
+
+/// The sled rental place's policy: the letter occurs from `first` to
+/// `second` times.
+#[derive(Clone, Copy, Debug)]
+pub struct LetterCount;
+
+impl PasswordPolicy for LetterCount {
+    fn check(&self, rule: &Rule, password: &str) -> Result<(), String> {
+        let occurrences = password.matches(rule.letter).count();
+
+        if (rule.first..=rule.second).contains(&occurrences) {
+            Ok(())
+        } else {
+            Err(format!(
+                "letter `{}` occurs {} times, allowed {}..={}",
+                rule.letter, occurrences, rule.first, rule.second
+            ))
+        }
+    }
+}
+
+impl fmt::Display for LetterCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "count")
+    }
+}
+
+/// The toboggan rental place's policy: the letter is at exactly one of the
+/// positions `first` and `second`, counting from 1, in either order.
+/// Positions count grapheme clusters, so `é` is one letter however it's
+/// encoded. Positions outside the password don't contain the letter, and the
+/// same position twice can't contain it exactly once.
+#[derive(Clone, Copy, Debug)]
+pub struct LetterPositions;
+
+impl PasswordPolicy for LetterPositions {
+    fn check(&self, rule: &Rule, password: &str) -> Result<(), String> {
+        let letters: Vec<&str> = password.graphemes(true).collect();
+        let letter = rule.letter.to_string();
+        let contains_letter = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|i| letters.get(i))
+                .is_some_and(|found| *found == letter)
+        };
+
+        match (contains_letter(rule.first), contains_letter(rule.second)) {
+            (true, false) | (false, true) => Ok(()),
+            (true, true) => Err(format!(
+                "positions {} and {} both contain `{}`",
+                rule.first, rule.second, rule.letter
+            )),
+            (false, false) => Err(format!(
+                "neither position {} nor {} contains `{}`",
+                rule.first, rule.second, rule.letter
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LetterPositions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "positions")
+    }
+}
+
+/// At least this many different characters, whatever the rule says.
+#[derive(Clone, Copy, Debug)]
+pub struct MinDistinct(pub usize);
+
+impl PasswordPolicy for MinDistinct {
+    fn check(&self, _: &Rule, password: &str) -> Result<(), String> {
+        let distinct = password.chars().collect::<HashSet<char>>().len();
+
+        if distinct >= self.0 {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} different characters, needs at least {}",
+                distinct, self.0
+            ))
+        }
+    }
+}
+
+impl fmt::Display for MinDistinct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "distinct:{}", self.0)
+    }
+}
+
+/// None of these substrings, whatever the rule says.
+#[derive(Clone, Debug)]
+pub struct ForbiddenSubstrings(pub Vec<String>);
+
+impl PasswordPolicy for ForbiddenSubstrings {
+    fn check(&self, _: &Rule, password: &str) -> Result<(), String> {
+        match self
+            .0
+            .iter()
+            .find(|substring| password.contains(substring.as_str()))
+        {
+            Some(substring) => Err(format!("contains `{}`", substring)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for ForbiddenSubstrings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "forbidden:{}", self.0.join(","))
+    }
+}
+
+/// Matches a regular expression, whatever the rule says. Anchor it with `^`
+/// and `$` to match the whole password.
+#[derive(Clone, Debug)]
+pub struct Matches(pub Regex);
+
+impl PasswordPolicy for Matches {
+    fn check(&self, _: &Rule, password: &str) -> Result<(), String> {
+        if self.0.is_match(password) {
+            Ok(())
+        } else {
+            Err(format!("doesn't match `{}`", self.0))
+        }
+    }
+}
+
+impl fmt::Display for Matches {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "regex:{}", self.0)
+    }
+}
+
+/// Looks up a policy by name:
+///
+/// - `count` and `positions` for the policies of part 1 and 2
+/// - `distinct:<n>` for [`MinDistinct`]
+/// - `forbidden:<substring>,<substring>...` for [`ForbiddenSubstrings`]
+/// - `regex:<regex>` for [`Matches`]
+pub fn policy(name: &str) -> Result<Box<dyn PasswordPolicy>, String> {
+    let (kind, argument) = match name.split_once(':') {
+        Some((kind, argument)) => (kind, Some(argument)),
+        None => (name, None),
+    };
+
+    match (kind, argument) {
+        ("count", None) => Ok(Box::new(LetterCount)),
+        ("positions", None) => Ok(Box::new(LetterPositions)),
+        ("distinct", Some(n)) => n
+            .parse()
+            .map(|n| Box::new(MinDistinct(n)) as Box<dyn PasswordPolicy>)
+            .map_err(|_| format!("expected a number of characters, found `{}`", n)),
+        ("forbidden", Some(substrings)) => Ok(Box::new(ForbiddenSubstrings(
+            substrings
+                .split(',')
+                .filter(|substring| !substring.is_empty())
+                .map(String::from)
+                .collect(),
+        ))),
+        ("regex", Some(regex)) => Regex::new(regex)
+            .map(|regex| Box::new(Matches(regex)) as Box<dyn PasswordPolicy>)
+            .map_err(|error| error.to_string()),
+        _ => Err(format!(
+            "expected `count`, `positions`, `distinct:<n>`, `forbidden:<substrings>` or `regex:<regex>`, found `{}`",
+            name
+        )),
+    }
+}
+
+struct Password {
+    line: usize,
+    password: String,
+    rule: Rule,
+}
+
+impl Password {
+    fn new(line: usize, password: String, first: usize, second: usize, letter: char) -> Password {
+        Password {
+            line,
+            password,
+            rule: Rule {
+                letter,
+                first,
+                second,
+            },
+        }
+    }
+
+    fn check<'a>(&'a self, policy: &'a dyn PasswordPolicy) -> Check<'a> {
+        let violation = policy.check(&self.rule, &self.password).err();
+        trace!(
+            "password_checked",
+            policy = policy,
+            password = self.password,
+            rule = self.rule,
+            valid = violation.is_none(),
+        );
+
+        Check {
+            line: self.line,
+            rule: &self.rule,
+            password: &self.password,
+            policy,
+            violation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day02 {
+        Day02::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(2));
+        assert_eq!(day.part2(), Answer::from(1));
+    }
+
+    #[test]
+    fn test_policies() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        let count = |name| day.count_valid(policy(name).unwrap().as_ref());
+
+        assert_eq!(count("count"), 2);
+        assert_eq!(count("positions"), 1);
+        assert_eq!(count("distinct:5"), 2);
+        assert_eq!(count("distinct:6"), 0);
+        assert_eq!(count("forbidden:cc,ab"), 1);
+        assert_eq!(count("forbidden:"), 3);
+        assert_eq!(count("regex:^[a-c]+$"), 1);
+    }
+
+    #[test]
+    fn test_check_all() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        let policies = vec![policy("count").unwrap(), policy("positions").unwrap()];
+        let violations: Vec<(usize, String, Option<String>)> = day
+            .check_all(&policies)
+            .into_iter()
+            .map(|check| (check.line, check.policy.to_string(), check.violation))
+            .collect();
+
+        assert_eq!(
+            violations,
+            vec![
+                (1, "count".into(), None),
+                (1, "positions".into(), None),
+                (
+                    2,
+                    "count".into(),
+                    Some("letter `b` occurs 0 times, allowed 1..=3".into())
+                ),
+                (
+                    2,
+                    "positions".into(),
+                    Some("neither position 1 nor 3 contains `b`".into())
+                ),
+                (3, "count".into(), None),
+                (
+                    3,
+                    "positions".into(),
+                    Some("positions 2 and 9 both contain `c`".into())
+                ),
+            ]
+        );
+    }
+
+    fn check_positions(line: &str) -> Result<(), String> {
+        let password = &parse_sample(line).passwords[0];
+        LetterPositions.check(&password.rule, &password.password)
+    }
+
+    #[test]
+    fn test_positions_in_any_order() {
+        assert_eq!(check_positions("3-1 a: abcd"), Ok(()));
+        assert_eq!(check_positions("1-3 a: abcd"), Ok(()));
+        assert_eq!(
+            check_positions("3-1 a: abad"),
+            Err("positions 3 and 1 both contain `a`".into())
+        );
+    }
+
+    #[test]
+    fn test_same_position_twice() {
+        assert_eq!(
+            check_positions("1-1 a: abc"),
+            Err("positions 1 and 1 both contain `a`".into())
+        );
+        assert_eq!(
+            check_positions("2-2 a: abc"),
+            Err("neither position 2 nor 2 contains `a`".into())
+        );
+    }
+
+    #[test]
+    fn test_positions_out_of_range() {
+        assert_eq!(
+            check_positions("5-6 a: ab"),
+            Err("neither position 5 nor 6 contains `a`".into())
+        );
+        assert_eq!(
+            check_positions("0-2 a: ab"),
+            Err("neither position 0 nor 2 contains `a`".into())
+        );
+        assert_eq!(check_positions("0-1 a: ab"), Ok(()));
+        assert_eq!(check_positions("1-9 a: abc"), Ok(()));
+    }
+
+    #[test]
+    fn test_positions_of_graphemes() {
+        // An e with a combining acute accent is one letter, not two, and
+        // neither of them is an e
+        assert_eq!(check_positions("2-3 b: e\u{301}bx"), Ok(()));
+        assert_eq!(
+            check_positions("2-3 b: e\u{301}bb"),
+            Err("positions 2 and 3 both contain `b`".into())
+        );
+        assert_eq!(
+            check_positions("1-3 e: e\u{301}xy"),
+            Err("neither position 1 nor 3 contains `e`".into())
+        );
+        // So is a flag, made of two regional indicators
+        assert_eq!(check_positions("1-2 b: 🇫🇷b"), Ok(()));
+    }
+
+    #[test]
+    fn test_policy_names() {
+        for name in [
+            "count",
+            "positions",
+            "distinct:8",
+            "forbidden:abc,xyz",
+            "regex:^a+$",
+        ] {
+            assert_eq!(policy(name).unwrap().to_string(), name);
+        }
+
+        assert!(policy("distinct:many").is_err());
+        assert!(policy("regex:(").is_err());
+        assert!(policy("length").is_err());
+        assert!(policy("count:3").is_err());
+    }
+}
@@ -0,0 +1,308 @@
+use std::{collections::HashSet, fmt, ops::RangeInclusive};
+
+use aoc::{trace, Answer, Grid, Input, ParseError, Solution};
+
+pub struct Day03 {
+    map: TobogganMap,
+}
+
+impl Day03 {
+    pub fn map(&self) -> &TobogganMap {
+        &self.map
+    }
+}
+
+/// The slopes part 2 checks.
+pub const SLOPES: [Slope; 5] = [
+    Slope::new(1, 1),
+    Slope::new(3, 1),
+    Slope::new(5, 1),
+    Slope::new(7, 1),
+    Slope::new(1, 2),
+];
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    fn parse(input: &Input) -> Result<Day03, ParseError> {
+        let trees = Grid::parse(input.numbered_lines(), "`.` or `#`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Day03 {
+            map: TobogganMap { trees },
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        self.map.count_trees(Slope::new(3, 1)).trees.into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.map.product_of_trees(&SLOPES).into()
+    }
+}
+
+/// How far the toboggan goes right for how far it goes down, each move. Slopes
+/// steeper than one square right per square down, like right 1 down 3, skip
+/// the rows in between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl Slope {
+    /// Panics if `down` is 0, the toboggan would never get to the bottom.
+    pub const fn new(right: usize, down: usize) -> Slope {
+        assert!(down > 0, "The toboggan has to go down");
+        Slope { right, down }
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+/// Where the toboggan went down a slope.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path {
+    /// Every square the toboggan moved onto, not counting the starting one.
+    /// Columns keep growing past the right edge of the map, as if it were
+    /// repeated that far.
+    pub visited: Vec<(usize, usize)>,
+    /// How many of those have a tree
+    pub trees: usize,
+}
+
+/// The slopes with the fewest or most trees on the way down, and how many.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Extreme {
+    pub trees: usize,
+    /// Every slope with that many trees, by how far they go right, then down
+    pub slopes: Vec<Slope>,
+}
+
+/// The open squares and trees of the slope, repeating endlessly to the right.
+pub struct TobogganMap {
+    trees: Grid<bool>,
+}
+
+impl TobogganMap {
+    pub fn width(&self) -> usize {
+        self.trees.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.trees.height()
+    }
+
+    /// Whether there's a tree at `(x, y)`, with `x` wrapping around.
+    pub fn is_tree(&self, x: usize, y: usize) -> bool {
+        self.width() > 0 && self.trees[(x % self.width(), y)]
+    }
+
+    /// Goes down the map from the top left corner. Whatever is on the starting
+    /// square doesn't count, the toboggan only counts what it moves onto.
+    pub fn count_trees(&self, slope: Slope) -> Path {
+        let visited: Vec<(usize, usize)> = (1..)
+            .map(|step| (step * slope.right, step * slope.down))
+            .take_while(|(_, y)| *y < self.height())
+            .collect();
+        let trees = visited.iter().filter(|(x, y)| self.is_tree(*x, *y)).count();
+
+        trace!(
+            "slope_done",
+            right = slope.right,
+            down = slope.down,
+            trees = trees
+        );
+        Path { visited, trees }
+    }
+
+    /// Trees on the way down each of `slopes`, multiplied together.
+    pub fn product_of_trees(&self, slopes: &[Slope]) -> usize {
+        slopes
+            .iter()
+            .map(|slope| self.count_trees(*slope).trees)
+            .product()
+    }
+
+    /// The map with the squares of `path` marked like in the puzzle, `O` where
+    /// they're open and `X` where they have a tree, one line per row. The map
+    /// is repeated to the right as many times as the path needs.
+    pub fn render(&self, path: &Path) -> String {
+        let visited: HashSet<(usize, usize)> = path.visited.iter().copied().collect();
+        let right_edge = path.visited.iter().map(|(x, _)| x + 1).max().unwrap_or(0);
+        let width = match self.width() {
+            0 => 0,
+            width => right_edge.div_ceil(width) * width,
+        }
+        .max(self.width());
+
+        let mut text = String::new();
+        for y in 0..self.height() {
+            for x in 0..width {
+                text.push(match (visited.contains(&(x, y)), self.is_tree(x, y)) {
+                    (true, true) => 'X',
+                    (true, false) => 'O',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                });
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Goes down every slope going right by one of `rights` and down by one of
+    /// `downs`, and returns the ones with the fewest trees and the ones with
+    /// the most, in that order. `None` if there are no slopes to go down.
+    ///
+    /// Going right by the width of the map or more lands on the same squares as
+    /// going right by less, so `0..=width - 1` covers every slope that goes down
+    /// one square at a time.
+    pub fn search_slopes(
+        &self,
+        rights: RangeInclusive<usize>,
+        downs: RangeInclusive<usize>,
+    ) -> Option<(Extreme, Extreme)> {
+        let mut fewest: Option<Extreme> = None;
+        let mut most: Option<Extreme> = None;
+
+        for right in rights {
+            for down in downs.clone().filter(|down| *down > 0) {
+                let slope = Slope::new(right, down);
+                let trees = self.count_trees(slope).trees;
+
+                keep_extreme(&mut fewest, slope, trees, |trees, best| trees < best);
+                keep_extreme(&mut most, slope, trees, |trees, best| trees > best);
+            }
+        }
+
+        Some((fewest?, most?))
+    }
+}
+
+/// Makes `slope` the extreme if `better` says its trees beat the extreme's, or
+/// adds it to the extreme's slopes if they're as many.
+fn keep_extreme(
+    extreme: &mut Option<Extreme>,
+    slope: Slope,
+    trees: usize,
+    better: impl Fn(usize, usize) -> bool,
+) {
+    match extreme {
+        Some(extreme) if extreme.trees == trees => extreme.slopes.push(slope),
+        Some(extreme) if !better(trees, extreme.trees) => {}
+        _ => {
+            *extreme = Some(Extreme {
+                trees,
+                slopes: vec![slope],
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day03 {
+        Day03::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(7));
+        assert_eq!(day.part2(), Answer::from(336));
+    }
+
+    #[test]
+    fn test_count_trees() {
+        let day = parse_sample(include_str!("../sample.txt"));
+
+        let trees: Vec<usize> = SLOPES
+            .iter()
+            .map(|slope| day.map().count_trees(*slope).trees)
+            .collect();
+        assert_eq!(trees, vec![2, 7, 3, 4, 2]);
+
+        let path = day.map().count_trees(Slope::new(2, 3));
+        assert_eq!(path.visited, vec![(2, 3), (4, 6), (6, 9)]);
+        assert_eq!(path.trees, 1);
+
+        let path = day.map().count_trees(Slope::new(13, 5));
+        assert_eq!(path.visited, vec![(13, 5), (26, 10)]);
+        assert_eq!(path.trees, 2);
+    }
+
+    #[test]
+    fn test_render() {
+        let map = parse_sample(include_str!("../sample.txt")).map;
+        let path = map.count_trees(Slope::new(3, 1));
+
+        // The example of the puzzle, as far as the toboggan gets
+        assert_eq!(
+            map.render(&path),
+            "..##.........##.........##.......\n\
+             #..O#...#..#...#...#..#...#...#..\n\
+             .#....X..#..#....#..#..#....#..#.\n\
+             ..#.#...#O#..#.#...#.#..#.#...#.#\n\
+             .#...##..#..X...##..#..#...##..#.\n\
+             ..#.##.......#.X#.......#.##.....\n\
+             .#.#.#....#.#.#.#.O..#.#.#.#....#\n\
+             .#........#.#........X.#........#\n\
+             #.##...#...#.##...#...#.X#...#...\n\
+             #...##....##...##....##...#X....#\n\
+             .#..#...#.#.#..#...#.#.#..#...X.#\n"
+        );
+
+        // Straight down stays on the map
+        let path = map.count_trees(Slope::new(0, 5));
+        assert_eq!(
+            map.render(&path).lines().map(|line| line.len()).max(),
+            Some(11)
+        );
+        assert!(map.render(&path).lines().nth(10).unwrap().starts_with('O'));
+    }
+
+    #[test]
+    fn test_search_slopes() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        let (fewest, most) = day.map().search_slopes(1..=11, 1..=1).unwrap();
+
+        assert_eq!(
+            fewest,
+            Extreme {
+                trees: 1,
+                slopes: vec![Slope::new(2, 1)],
+            }
+        );
+        assert_eq!(
+            most,
+            Extreme {
+                trees: 7,
+                slopes: vec![Slope::new(3, 1)],
+            }
+        );
+
+        // Going down 11 leaves the map straight away
+        let (fewest, _) = day.map().search_slopes(1..=2, 10..=11).unwrap();
+        assert_eq!(fewest.trees, 0);
+        assert_eq!(
+            fewest.slopes,
+            vec![Slope::new(1, 11), Slope::new(2, 10), Slope::new(2, 11)]
+        );
+
+        let nowhere = RangeInclusive::new(1, 0);
+        assert_eq!(day.map().search_slopes(nowhere, 1..=1), None);
+        assert_eq!(day.map().search_slopes(1..=3, 0..=0), None);
+    }
+}
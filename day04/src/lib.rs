@@ -0,0 +1,271 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::collections::HashSet;
+
+use aoc::{parse::words, trace, Answer, Input, Line, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day04 {
+    passports: Vec<Vec<(String, String)>>,
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    fn parse(input: &Input) -> Result<Day04, ParseError> {
+        // Passports span several lines and are separated by blank lines
+        let passports = input
+            .numbered_groups()
+            .map(|passport| parse_passport(&passport))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day04 { passports })
+    }
+
+    fn part1(&self) -> Answer {
+        self.passports
+            .iter()
+            .filter(|passport| has_required_fields(passport))
+            .count()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.passports
+            .iter()
+            .filter(|passport| validate_passport(passport))
+            .count()
+            .into()
+    }
+}
+
+lazy_static! {
+    static ref HEIGHT_REGEX: Regex = Regex::new(r"(\d+)(cm|in)").expect("Failed to compile regex");
+    static ref PID_REGEX: Regex = Regex::new(r"^\d{9}$").expect("Failed to compile regex");
+    static ref HCL_REGEX: Regex =
+        Regex::new(r"^#[[:xdigit:]]{6}$").expect("Failed to compile regex");
+}
+
+static REQUIRED_FIELDS: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+/// Splits each line of a passport into its `key:value` fields.
+fn parse_passport(lines: &[Line]) -> Result<Vec<(String, String)>, ParseError> {
+    let mut fields = vec![];
+
+    for line in lines {
+        for (column, field) in words(line) {
+            let (key, value) = line.parse(|_| match field.split_once(':') {
+                Some((key, value)) if key == "cid" || REQUIRED_FIELDS.contains(&key) => {
+                    Ok((key, value))
+                }
+                Some((key, _)) => Err(ParseError::unexpected(
+                    "one of `byr`, `iyr`, `eyr`, `hgt`, `hcl`, `ecl`, `pid` or `cid`",
+                    key,
+                )
+                .at_column(column)),
+                None => {
+                    Err(ParseError::unexpected("a field like `key:value`", field).at_column(column))
+                }
+            })?;
+
+            fields.push((key.into(), value.into()));
+        }
+    }
+
+    Ok(fields)
+}
+
+fn has_required_fields(passport: &[(String, String)]) -> bool {
+    let fields: HashSet<&str> = passport.iter().map(|(key, _)| key.as_str()).collect();
+    let missing: Vec<&str> = REQUIRED_FIELDS
+        .iter()
+        .filter(|field| !fields.contains(*field))
+        .copied()
+        .collect();
+
+    trace!("passport_checked", missing = missing.join(","));
+    missing.is_empty()
+}
+
+fn validate_passport(passport: &[(String, String)]) -> bool {
+    let mut builder = PassportBuilder::new();
+
+    for (key, value) in passport {
+        match (key.as_str(), value.as_str()) {
+            ("byr", value) => builder.byr(value),
+            ("iyr", value) => builder.iyr(value),
+            ("eyr", value) => builder.eyr(value),
+            ("hgt", value) => builder.hgt(value),
+            ("pid", value) => builder.pid(value),
+            ("ecl", value) => builder.ecl(value),
+            ("hcl", value) => builder.hcl(value),
+            ("cid", value) => builder.cid(value),
+            (other, _) => unreachable!("Unknown field {} should have failed to parse", other),
+        }
+    }
+
+    trace!(
+        "passport_checked",
+        invalid = builder.invalid_fields().join(",")
+    );
+    builder.is_valid()
+}
+
+struct PassportBuilder {
+    byr: Option<usize>,
+    iyr: Option<usize>,
+    eyr: Option<usize>,
+    hgt: Option<String>,
+    hcl: Option<String>,
+    ecl: Option<String>,
+    pid: Option<String>,
+}
+
+impl PassportBuilder {
+    fn new() -> PassportBuilder {
+        PassportBuilder {
+            byr: None,
+            iyr: None,
+            eyr: None,
+            hgt: None,
+            hcl: None,
+            ecl: None,
+            pid: None,
+        }
+    }
+
+    fn byr(&mut self, byr: &str) {
+        match byr.parse::<usize>() {
+            Ok(n) if (1920..=2002).contains(&n) => self.byr = Some(n),
+            _ => {}
+        }
+    }
+
+    fn iyr(&mut self, iyr: &str) {
+        match iyr.parse::<usize>() {
+            Ok(n) if (2010..=2020).contains(&n) => self.iyr = Some(n),
+            _ => {}
+        }
+    }
+
+    fn eyr(&mut self, eyr: &str) {
+        match eyr.parse::<usize>() {
+            Ok(n) if (2020..=2030).contains(&n) => self.eyr = Some(n),
+            _ => {}
+        }
+    }
+
+    fn hgt(&mut self, hgt: &str) {
+        let captures = match HEIGHT_REGEX.captures(hgt) {
+            Some(captures) => captures,
+            _ => return,
+        };
+
+        match (
+            captures.get(1).expect("Invalid capture").as_str(),
+            captures.get(2).expect("Invalid capture").as_str(),
+        ) {
+            (n, "in")
+                if n.parse::<usize>()
+                    .is_ok_and(|height| (59..=76).contains(&height)) =>
+            {
+                self.hgt = Some(hgt.into());
+            }
+            (n, "cm")
+                if n.parse::<usize>()
+                    .is_ok_and(|height| (150..=193).contains(&height)) =>
+            {
+                self.hgt = Some(hgt.into());
+            }
+            _ => {}
+        }
+    }
+
+    fn pid(&mut self, pid: &str) {
+        if PID_REGEX.is_match(pid) {
+            self.pid = Some(pid.into());
+        }
+    }
+
+    fn ecl(&mut self, ecl: &str) {
+        match ecl {
+            "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth" => self.ecl = Some(ecl.into()),
+            _ => {}
+        }
+    }
+
+    fn hcl(&mut self, hcl: &str) {
+        if HCL_REGEX.is_match(hcl) {
+            self.hcl = Some(hcl.into());
+        }
+    }
+
+    // no-op, we don't care about the cid
+    fn cid(&self, _cid: &str) {}
+
+    /// The required fields that are missing or didn't pass validation.
+    fn invalid_fields(&self) -> Vec<&'static str> {
+        let fields = [
+            self.byr.is_some(),
+            self.iyr.is_some(),
+            self.eyr.is_some(),
+            self.hgt.is_some(),
+            self.hcl.is_some(),
+            self.ecl.is_some(),
+            self.pid.is_some(),
+        ];
+
+        REQUIRED_FIELDS
+            .iter()
+            .zip(fields.iter())
+            .filter(|(_, valid)| !**valid)
+            .map(|(field, _)| *field)
+            .collect()
+    }
+
+    fn is_valid(&self) -> bool {
+        matches!(
+            (self.byr, &self.ecl, self.eyr, self.iyr, &self.hgt, &self.hcl, &self.pid,),
+            (
+                Some(_),
+                Some(_),
+                Some(_),
+                Some(_),
+                Some(_),
+                Some(_),
+                Some(_)
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day04 {
+        Day04::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(2));
+        assert_eq!(day.part2(), Answer::from(2));
+    }
+
+    #[test]
+    fn test_invalid_passports() {
+        let day = parse_sample(include_str!("../sample2.txt"));
+        assert_eq!(day.part1(), Answer::from(4));
+        assert_eq!(day.part2(), Answer::from(0));
+    }
+
+    #[test]
+    fn test_valid_passports() {
+        let day = parse_sample(include_str!("../sample3.txt"));
+        assert_eq!(day.part1(), Answer::from(4));
+        assert_eq!(day.part2(), Answer::from(4));
+    }
+}
@@ -0,0 +1,120 @@
+use aoc::{trace, Answer, Input, ParseError, Solution};
+
+pub struct Day05 {
+    seat_ids: Vec<usize>,
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    fn parse(input: &Input) -> Result<Day05, ParseError> {
+        let mut seat_ids: Vec<usize> = input
+            .numbered_lines()
+            .map(|line| line.parse(seat_id))
+            .collect::<Result<_, _>>()?;
+        if seat_ids.is_empty() {
+            return Err(input.end().missing("a boarding pass"));
+        }
+        seat_ids.sort();
+
+        Ok(Day05 { seat_ids })
+    }
+
+    fn part1(&self) -> Answer {
+        (*self.seat_ids.last().unwrap()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let offset = *self.seat_ids.first().unwrap();
+
+        self.seat_ids
+            .iter()
+            .enumerate()
+            .find_map(|(i, seat_id)| {
+                if *seat_id - i != offset {
+                    trace!("gap_found", before = self.seat_ids[i - 1], after = seat_id);
+                    Some(seat_id - 1)
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+            .into()
+    }
+}
+
+fn seat_id(seat: &str) -> Result<usize, ParseError> {
+    let mut chars = seat.chars().enumerate();
+
+    let mut rows = (0_usize, 127);
+    for (i, partition) in chars.by_ref().take(7) {
+        rows = match partition {
+            'F' => (rows.0, rows.1 - (rows.1 - rows.0).div_ceil(2)),
+            'B' => (rows.0 + (rows.1 - rows.0).div_ceil(2), rows.1),
+            other => {
+                return Err(ParseError::unexpected("`F` or `B`", other.to_string()).at_column(i + 1))
+            }
+        };
+    }
+
+    let mut columns = (0_usize, 7);
+    for (i, partition) in chars.by_ref().take(3) {
+        columns = match partition {
+            'L' => (columns.0, columns.1 - (columns.1 - columns.0).div_ceil(2)),
+            'R' => (columns.0 + (columns.1 - columns.0).div_ceil(2), columns.1),
+            other => {
+                return Err(ParseError::unexpected("`L` or `R`", other.to_string()).at_column(i + 1))
+            }
+        };
+    }
+
+    match (seat.chars().count(), chars.next()) {
+        (10, None) => {
+            trace!(
+                "seat_decoded",
+                seat = seat,
+                row = rows.0,
+                column = columns.0
+            );
+            Ok(rows.0 * 8 + columns.0)
+        }
+        (_, Some((i, other))) => {
+            Err(ParseError::unexpected("the end of the line", other.to_string()).at_column(i + 1))
+        }
+        (length, None) => {
+            Err(ParseError::missing("a seat like `FBFBBFFRLR`").at_column(length + 1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day05 {
+        Day05::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_seat_id() {
+        assert_eq!(seat_id("FBFBBFFRLR"), Ok(357));
+        assert_eq!(seat_id("BFFFBBFRRR"), Ok(567));
+        assert_eq!(seat_id("FFFBBBFRRR"), Ok(119));
+        assert_eq!(seat_id("BBFFBBFRLL"), Ok(820));
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(820));
+    }
+
+    #[test]
+    fn test_no_boarding_passes() {
+        let error = Day05::parse(&Input::from("")).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "missing a boarding pass at line 1, column 1"
+        );
+    }
+}
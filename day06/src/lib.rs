@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use aoc::{trace, Answer, Input, Line, ParseError, Solution};
+
+pub struct Day06 {
+    groups: Vec<Vec<HashSet<char>>>,
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    fn parse(input: &Input) -> Result<Day06, ParseError> {
+        let groups = input
+            .numbered_groups()
+            .map(|group| responses_to_sets(group.into_iter()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day06 { groups })
+    }
+
+    fn part1(&self) -> Answer {
+        self.groups
+            .iter()
+            .map(|group| {
+                let answered = answered_by_any(group.iter());
+                trace!("group_counted", people = group.len(), answered = answered);
+                answered
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.groups
+            .iter()
+            .map(|group| {
+                let answered = answered_by_all(group.iter());
+                trace!("group_counted", people = group.len(), answered = answered);
+                answered
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+fn responses_to_sets<'a>(
+    responses: impl Iterator<Item = Line<'a>>,
+) -> Result<Vec<HashSet<char>>, ParseError> {
+    responses
+        .map(|response| {
+            response.parse(|text| {
+                text.chars()
+                    .enumerate()
+                    .map(|(i, answer)| match answer {
+                        'a'..='z' => Ok(answer),
+                        other => Err(ParseError::unexpected(
+                            "a letter from `a` to `z`",
+                            other.to_string(),
+                        )
+                        .at_column(i + 1)),
+                    })
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+fn answered_by_any<'a>(responses: impl Iterator<Item = &'a HashSet<char>>) -> usize {
+    responses
+        .fold(HashSet::new(), |total, response| {
+            total.union(response).copied().collect()
+        })
+        .len()
+}
+
+fn answered_by_all<'a>(mut responses: impl Iterator<Item = &'a HashSet<char>>) -> usize {
+    let first = responses.next().unwrap().clone();
+
+    responses
+        .fold(first, |total, response| {
+            total.intersection(response).copied().collect()
+        })
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day06 {
+        Day06::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(11));
+        assert_eq!(day.part2(), Answer::from(6));
+    }
+}
@@ -0,0 +1,159 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc::{
+    parse::{column, number},
+    trace, Answer, Input, ParseError, Solution,
+};
+use regex::Regex;
+
+pub struct Day07 {
+    bags: BagRules,
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    fn parse(input: &Input) -> Result<Day07, ParseError> {
+        let mut bags = BagRules::new();
+
+        for line in input.numbered_lines() {
+            line.parse(|text| {
+                let captures = CONTAINING_BAG_REGEX.captures(text).ok_or_else(|| {
+                    ParseError::unexpected(
+                        "a rule like `light red bags contain 1 bright white bag.`",
+                        text,
+                    )
+                })?;
+                let container_bag_name = &captures[1];
+                let contents = captures.get(2).unwrap();
+
+                if contents.as_str() == "no other bags" {
+                    return Ok(());
+                }
+
+                let mut start = contents.start();
+                for contained in contents.as_str().split(", ") {
+                    let bag_captures =
+                        CONTAINED_BAGS_REGEX.captures(contained).ok_or_else(|| {
+                            ParseError::unexpected(
+                                "a bag count like `1 bright white bag`",
+                                contained,
+                            )
+                            .at_column(column(text, start))
+                        })?;
+                    let bag_count = number(&bag_captures[1], column(text, start))?;
+                    let bag_name = &bag_captures[2];
+                    bags.add(container_bag_name, bag_name, bag_count);
+
+                    start += contained.len() + 2;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(Day07 { bags })
+    }
+
+    fn part1(&self) -> Answer {
+        self.bags.count_total_to("shiny gold").into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.bags.count_bags_from("shiny gold").into()
+    }
+}
+
+lazy_static! {
+    static ref CONTAINING_BAG_REGEX: Regex =
+        Regex::new(r"^([\w\s]+?) bags contain (.+)\.$").expect("Failed to compile regex");
+    static ref CONTAINED_BAGS_REGEX: Regex =
+        Regex::new(r"^(\d+) ([\w\s]+?) bags?$").expect("Failed to compile regex");
+}
+
+struct BagRules {
+    bag_rules: HashMap<String, HashMap<String, usize>>,
+    bag_rules_inv: HashMap<String, HashSet<String>>,
+}
+
+impl BagRules {
+    fn new() -> BagRules {
+        BagRules {
+            bag_rules: HashMap::new(),
+            bag_rules_inv: HashMap::new(),
+        }
+    }
+
+    fn add(&mut self, from: &str, to: &str, count: usize) {
+        self.bag_rules
+            .entry(from.into())
+            .or_default()
+            .insert(to.into(), count);
+
+        self.bag_rules_inv
+            .entry(to.into())
+            .or_default()
+            .insert(from.into());
+    }
+
+    fn count_bags_from(&self, from: &str) -> usize {
+        let count = self
+            .bag_rules
+            .get(from)
+            .map(|edge| {
+                edge.iter()
+                    .map(|(k, v)| self.count_bags_from(k) * v + v)
+                    .sum()
+            })
+            .unwrap_or(0);
+
+        trace!("bag_expanded", bag = from, contains = count);
+        count
+    }
+
+    fn collect_container_bags(&self, to: &str) -> HashSet<String> {
+        let containers: HashSet<String> = self
+            .bag_rules_inv
+            .get(to)
+            .map(|set| {
+                set.iter().fold(set.clone(), |acc, container| {
+                    acc.union(&self.collect_container_bags(container))
+                        .cloned()
+                        .collect()
+                })
+            })
+            .unwrap_or_default();
+
+        trace!("bag_expanded", bag = to, contained_by = containers.len());
+        containers
+    }
+
+    fn count_total_to(&self, to: &str) -> usize {
+        self.collect_container_bags(to).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day07 {
+        Day07::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(4));
+        assert_eq!(day.part2(), Answer::from(32));
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        let day = parse_sample(include_str!("../sample2.txt"));
+        assert_eq!(day.part2(), Answer::from(126));
+    }
+}
@@ -0,0 +1,227 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::collections::HashSet;
+
+use aoc::{parse::number, trace, Answer, Input, Line, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day08 {
+    console: Console,
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    fn parse(input: &Input) -> Result<Day08, ParseError> {
+        let console = Console::parse(input.numbered_lines())?;
+        if console.code.is_empty() {
+            return Err(input.end().missing("an instruction"));
+        }
+
+        Ok(Day08 { console })
+    }
+
+    fn part1(&self) -> Answer {
+        self.console.run_until_end_or_repeat().into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.console.look_for_fix().into()
+    }
+}
+
+struct Console {
+    code: Vec<Instruction>,
+}
+
+impl Console {
+    fn new(code: Vec<Instruction>) -> Console {
+        Console { code }
+    }
+
+    fn parse<'a>(code: impl Iterator<Item = Line<'a>>) -> Result<Console, ParseError> {
+        let code = code
+            .map(|line| line.parse(Instruction::parse))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Console::new(code))
+    }
+
+    fn run_until_end_or_repeat(&self) -> isize {
+        let mut vm = Vm::new();
+        vm.run(&self.code.iter().collect());
+
+        trace!(
+            "stopped",
+            line = vm.program_counter + 1,
+            accumulator = vm.accumulator
+        );
+        vm.accumulator
+    }
+
+    fn look_for_fix(&self) -> isize {
+        self.code
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| {
+                matches!(instruction.operation, Operation::Nop(_) | Operation::Jmp(_))
+            })
+            .find_map(|(index, instruction)| {
+                let mut updated_code: Vec<&Instruction> = self.code.iter().collect();
+
+                let updated_instruction = match instruction.operation {
+                    Operation::Nop(n) => Instruction::new(Operation::Jmp(n)),
+                    Operation::Jmp(n) => Instruction::new(Operation::Nop(n)),
+                    _ => unreachable!(),
+                };
+
+                updated_code[index] = &updated_instruction;
+
+                let mut vm = Vm::new();
+                vm.run(&updated_code);
+
+                trace!(
+                    "fix_tried",
+                    line = index + 1,
+                    finished = vm.finished_successfully(),
+                    accumulator = vm.accumulator,
+                );
+
+                if vm.finished_successfully() {
+                    Some(vm.accumulator)
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    }
+}
+
+struct Vm {
+    program_counter: usize,
+    accumulator: isize,
+    visited: HashSet<usize>,
+    status: VmStatus,
+}
+
+#[derive(PartialEq)]
+enum VmStatus {
+    Ready,
+    Looped,
+    /// Jumped somewhere before the first or past the last instruction.
+    OutOfBounds,
+    Finished,
+}
+
+impl Vm {
+    fn new() -> Vm {
+        Vm {
+            program_counter: 0,
+            accumulator: 0,
+            visited: HashSet::new(),
+            status: VmStatus::Ready,
+        }
+    }
+
+    fn run(&mut self, code: &Vec<&Instruction>) {
+        while !self.visited.contains(&self.program_counter) && self.program_counter < code.len() {
+            self.visited.insert(self.program_counter);
+
+            match code
+                .get(self.program_counter)
+                .expect("program_counter out of bounds")
+                .operation
+            {
+                Operation::Nop(_) => self.program_counter += 1,
+                Operation::Acc(n) => {
+                    self.accumulator += n;
+                    self.program_counter += 1;
+                }
+                Operation::Jmp(n) => match (self.program_counter as isize).checked_add(n) {
+                    Some(target) if target >= 0 => self.program_counter = target as usize,
+                    _ => {
+                        self.status = VmStatus::OutOfBounds;
+                        return;
+                    }
+                },
+            }
+        }
+
+        self.status = if self.program_counter == code.len() {
+            VmStatus::Finished
+        } else if self.program_counter > code.len() {
+            VmStatus::OutOfBounds
+        } else {
+            VmStatus::Looped
+        };
+    }
+
+    fn finished_successfully(&self) -> bool {
+        self.status == VmStatus::Finished
+    }
+}
+
+#[derive(Clone)]
+struct Instruction {
+    operation: Operation,
+}
+
+lazy_static! {
+    static ref INSTRUCTION_REGEX: Regex =
+        Regex::new(r"^(\w+) ([+-]\d+)$").expect("Failed to compile regex");
+}
+
+impl Instruction {
+    fn new(operation: Operation) -> Instruction {
+        Instruction { operation }
+    }
+
+    fn parse(code: &str) -> Result<Instruction, ParseError> {
+        let captures = INSTRUCTION_REGEX
+            .captures(code)
+            .ok_or_else(|| ParseError::unexpected("an instruction like `acc +1`", code))?;
+        let value = number(&captures[2], captures[1].len() + 2)?;
+
+        match &captures[1] {
+            "nop" => Ok(Instruction::new(Operation::Nop(value))),
+            "acc" => Ok(Instruction::new(Operation::Acc(value))),
+            "jmp" => Ok(Instruction::new(Operation::Jmp(value))),
+            other => Err(ParseError::unexpected(
+                "one of `nop`, `acc` or `jmp`",
+                other,
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Operation {
+    Nop(isize),
+    Acc(isize),
+    Jmp(isize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day08 {
+        Day08::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(5));
+        assert_eq!(day.part2(), Answer::from(8));
+    }
+
+    #[test]
+    fn test_fix_jumping_out_of_bounds() {
+        // Turning the `nop -5` into a jump would land before the first instruction
+        let day = parse_sample("nop -5\nacc +3\njmp -1\nacc +1\n");
+        assert_eq!(day.part1(), Answer::from(3));
+        assert_eq!(day.part2(), Answer::from(4));
+    }
+}
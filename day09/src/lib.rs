@@ -0,0 +1,164 @@
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day09 {
+    code: Vec<usize>,
+}
+
+impl Day09 {
+    fn first_invalid_number(&self) -> usize {
+        let mut decoder = XmasDecoder::new(25);
+        decoder.decode(self.code.iter().copied())
+    }
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    fn parse(input: &Input) -> Result<Day09, ParseError> {
+        let code: Vec<usize> = input
+            .numbered_lines()
+            .map(|line| line.parse(|text| number(text, 1)))
+            .collect::<Result<_, _>>()?;
+        if code.is_empty() {
+            return Err(input.end().missing("a number"));
+        }
+
+        Ok(Day09 { code })
+    }
+
+    fn part1(&self) -> Answer {
+        self.first_invalid_number().into()
+    }
+
+    fn part2(&self) -> Answer {
+        find_weakness(&self.code, self.first_invalid_number()).into()
+    }
+}
+
+struct XmasDecoder {
+    preamble: usize,
+    previous_numbers: Vec<usize>,
+}
+
+impl XmasDecoder {
+    fn new(preamble: usize) -> XmasDecoder {
+        XmasDecoder {
+            preamble,
+            previous_numbers: Vec::with_capacity(preamble),
+        }
+    }
+
+    fn decode(&mut self, mut stream: impl Iterator<Item = usize>) -> usize {
+        self.previous_numbers
+            .extend(stream.by_ref().take(self.preamble));
+
+        stream
+            .find(|number_in_stream| {
+                let found_pair =
+                    self.previous_numbers
+                        .iter()
+                        .enumerate()
+                        .any(|(i, number_in_previous)| {
+                            // The two numbers of the pair have different values
+                            match number_in_stream.checked_sub(*number_in_previous) {
+                                Some(goal) if goal != *number_in_previous => self
+                                    .previous_numbers
+                                    .iter()
+                                    .skip(i + 1)
+                                    .any(|pair| *pair == goal),
+                                _ => false,
+                            }
+                        });
+
+                if !found_pair {
+                    trace!(
+                        "invalid_number_found",
+                        number = number_in_stream,
+                        previous = self.previous_numbers.len(),
+                    );
+                    return true;
+                }
+
+                self.previous_numbers.remove(0);
+                self.previous_numbers.push(*number_in_stream);
+
+                false
+            })
+            .unwrap()
+    }
+}
+
+fn find_weakness(code: &[usize], goal: usize) -> usize {
+    let code_length = code.len();
+
+    code.iter()
+        .enumerate()
+        .find_map(|(range_start, initial)| {
+            let mut sum = *initial;
+            let mut offset = 1;
+
+            let mut min = *initial;
+            let mut max = *initial;
+
+            while sum < goal && range_start + offset < code_length {
+                let value = code[range_start + offset];
+                sum += value;
+
+                if value < min {
+                    min = value;
+                }
+
+                if value > max {
+                    max = value;
+                }
+
+                offset += 1;
+            }
+
+            // The set has to be at least two numbers, `goal` on its own doesn't count
+            if sum == goal && offset > 1 {
+                trace!(
+                    "range_found",
+                    start = range_start,
+                    length = offset,
+                    min = min,
+                    max = max,
+                );
+                Some(min + max)
+            } else {
+                None
+            }
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day09 {
+        Day09::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+
+        // The example uses a preamble of 5 numbers instead of 25
+        let invalid_number = XmasDecoder::new(5).decode(day.code.iter().copied());
+        assert_eq!(invalid_number, 127);
+        assert_eq!(find_weakness(&day.code, invalid_number), 62);
+    }
+
+    #[test]
+    fn test_pair_of_different_numbers() {
+        // 6 is only 3 + 3
+        let invalid_number = XmasDecoder::new(3).decode([3, 1, 3, 6, 4].iter().copied());
+        assert_eq!(invalid_number, 6);
+    }
+
+    #[test]
+    fn test_weakness_of_two_numbers_or_more() {
+        assert_eq!(find_weakness(&[1, 9, 4, 5, 3], 9), 9);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day10 {
+    // Sorted, including the charging outlet's 0 jolts
+    joltages: Vec<usize>,
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    fn parse(input: &Input) -> Result<Day10, ParseError> {
+        let mut joltages: Vec<usize> = input
+            .numbered_lines()
+            .map(|line| line.parse(|text| number(text, 1)))
+            .collect::<Result<_, _>>()?;
+
+        joltages.push(0);
+        joltages.sort();
+
+        Ok(Day10 { joltages })
+    }
+
+    // Differences of 2 jolts are fine but don't count. There's no chain using
+    // every adapter if two of them are the same or more than 3 jolts apart.
+    fn part1(&self) -> Answer {
+        let mut differences_of_1 = 0;
+        let mut differences_of_3 = 0;
+        for window in self.joltages.windows(2) {
+            match window[1] - window[0] {
+                1 => differences_of_1 += 1,
+                2 => {}
+                3 => differences_of_3 += 1,
+                other => {
+                    trace!("chain_broken", joltage = window[0], difference = other);
+                    return Answer::None;
+                }
+            }
+        }
+
+        // The device is always 3 jolts above the highest adapter
+        trace!(
+            "differences_counted",
+            ones = differences_of_1,
+            threes = differences_of_3 + 1,
+        );
+        (differences_of_1 * (differences_of_3 + 1)).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let device_joltage = self.joltages.iter().max().unwrap() + 3;
+        let mut joltages = self.joltages.clone();
+        joltages.reverse();
+        joltages_to(device_joltage, &joltages, &mut HashMap::new()).into()
+    }
+}
+
+// available_joltages is expected to be sorted in descending order. `memo` keeps the
+// result for each target already computed for these same joltages.
+fn joltages_to(
+    target: usize,
+    available_joltages: &[usize],
+    memo: &mut HashMap<usize, usize>,
+) -> usize {
+    if target == 0 {
+        return 1;
+    }
+
+    if let Some(memoized_value) = memo.get(&target) {
+        return *memoized_value;
+    }
+
+    let result = available_joltages
+        .iter()
+        .filter(|n| **n < target)
+        .take_while(|joltage| target - **joltage <= 3)
+        .fold(0, |acc, adapter| {
+            acc + joltages_to(*adapter, available_joltages, memo)
+        });
+
+    trace!(
+        "arrangements_counted",
+        joltage = target,
+        arrangements = result
+    );
+    memo.insert(target, result);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day10 {
+        Day10::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(35));
+        assert_eq!(day.part2(), Answer::from(8));
+    }
+
+    #[test]
+    fn test_larger_sample() {
+        let day = parse_sample(include_str!("../sample2.txt"));
+        assert_eq!(day.part1(), Answer::from(220));
+        assert_eq!(day.part2(), Answer::from(19208));
+    }
+
+    #[test]
+    fn test_difference_of_2() {
+        let day = parse_sample("1\n3\n4\n");
+        assert_eq!(day.part1(), Answer::from(2));
+        assert_eq!(day.part2(), Answer::from(3));
+    }
+
+    #[test]
+    fn test_broken_chain() {
+        let day = parse_sample("1\n10\n");
+        assert_eq!(day.part1(), Answer::None);
+        assert_eq!(day.part2(), Answer::from(0));
+
+        let day = parse_sample("1\n1\n");
+        assert_eq!(day.part1(), Answer::None);
+    }
+}
@@ -0,0 +1,213 @@
+use std::io;
+
+use aoc::{
+    automaton::Bounded, grid::NEIGHBOURS_8, trace, Answer, Automaton, Canvas, Color, Frame, Grid,
+    Input, Line, ParseError, Part, Rule, Solution, SparseGrid, Visualize,
+};
+
+pub struct Day11 {
+    seating_area: SeatingArea,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    fn parse(input: &Input) -> Result<Day11, ParseError> {
+        Ok(Day11 {
+            seating_area: SeatingArea::parse(input.numbered_lines())?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut seating_area = self.seating_area.clone();
+        seating_area.simulate();
+        seating_area.occupied_seats().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut seating_area = self.seating_area.clone();
+        seating_area.simulate_with_sight();
+        seating_area.occupied_seats().into()
+    }
+}
+
+impl Visualize for Day11 {
+    /// Every round of people moving, until nobody does.
+    fn visualize(&self, part: Part, canvas: &mut dyn Canvas) -> io::Result<()> {
+        let mut seating_area = self.seating_area.clone();
+        let mut automaton = match part {
+            Part::One => seating_area.automaton(Some(1), 4),
+            Part::Two => seating_area.automaton(None, 5),
+        };
+
+        loop {
+            seating_area.occupy(automaton.live());
+            canvas.draw(&seating_area.frame())?;
+
+            if !automaton.step() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Seat {
+    Free,
+    Occupied,
+}
+
+#[derive(Clone)]
+struct SeatingArea {
+    seats: Grid<Option<Seat>>,
+}
+
+impl SeatingArea {
+    fn parse<'a>(initial_state: impl Iterator<Item = Line<'a>>) -> Result<SeatingArea, ParseError> {
+        let seats = Grid::parse(initial_state, "`.`, `L` or `#`", |c| match c {
+            '.' => Some(None),
+            'L' => Some(Some(Seat::Free)),
+            '#' => Some(Some(Seat::Occupied)),
+            _ => None,
+        })?;
+
+        Ok(SeatingArea { seats })
+    }
+
+    fn simulate(&mut self) {
+        self.simulate_with(Some(1), 4);
+    }
+
+    fn simulate_with_sight(&mut self) {
+        self.simulate_with(None, 5);
+    }
+
+    /// Lets people sit down and stand up until nobody does, see
+    /// [`SeatingArea::automaton`].
+    fn simulate_with(&mut self, limit: Option<usize>, tolerance: usize) {
+        let mut automaton = self.automaton(limit, tolerance);
+        let rounds = automaton.run_until_stable();
+        trace!(
+            "settled",
+            rounds = rounds,
+            occupied = automaton.live().len()
+        );
+        self.occupy(automaton.live());
+    }
+
+    /// People sitting down and standing up as an automaton of occupied seats.
+    /// Each looks at the first seat in every direction, up to `limit` cells
+    /// away, and leaves once `tolerance` of those are occupied.
+    fn automaton(
+        &self,
+        limit: Option<usize>,
+        tolerance: usize,
+    ) -> Automaton<[isize; 2], Bounded<[isize; 2]>> {
+        let topology = Bounded::new(self.seat_positions().map(|(x, y)| {
+            let visible = NEIGHBOURS_8
+                .iter()
+                .filter_map(|direction| self.seat_in_direction(x, y, *direction, limit))
+                .map(|(x, y)| [x, y])
+                .collect();
+            ([x, y], visible)
+        }));
+        let occupied = self
+            .seat_positions()
+            .filter(|(x, y)| matches!(self.seats[(*x as usize, *y as usize)], Some(Seat::Occupied)))
+            .map(|(x, y)| [x, y])
+            .collect();
+        let rule = Rule {
+            birth: 0..=0,
+            survival: 0..=tolerance - 1,
+        };
+
+        Automaton::new(topology, rule, occupied)
+    }
+
+    /// Occupies the seats in `occupied` and frees every other one.
+    fn occupy(&mut self, occupied: &SparseGrid<[isize; 2]>) {
+        for (x, y) in self.seats.positions() {
+            if let Some(seat) = &mut self.seats[(x, y)] {
+                *seat = if occupied.contains([x as isize, y as isize]) {
+                    Seat::Occupied
+                } else {
+                    Seat::Free
+                };
+            }
+        }
+    }
+
+    /// The floor in black, free seats in green and occupied ones in red.
+    fn frame(&self) -> Frame {
+        self.seats.map(|seat| match seat {
+            None => Color::BLACK,
+            Some(Seat::Free) => Color::GREEN,
+            Some(Seat::Occupied) => Color::RED,
+        })
+    }
+
+    fn seat_positions(&self) -> impl Iterator<Item = (isize, isize)> + '_ {
+        self.seats
+            .iter()
+            .filter(|(_, seat)| seat.is_some())
+            .map(|((x, y), _)| (x as isize, y as isize))
+    }
+
+    /// The first seat from `(x, y)` going in a direction, if there's one no
+    /// more than `limit` cells away.
+    fn seat_in_direction(
+        &self,
+        x: isize,
+        y: isize,
+        (dx, dy): (isize, isize),
+        limit: Option<usize>,
+    ) -> Option<(isize, isize)> {
+        let (mut x, mut y) = (x + dx, y + dy);
+        let mut distance = 1;
+
+        while let Some(cell) = self.seats.get(x, y) {
+            if limit.is_some_and(|n| distance > n) {
+                break;
+            }
+            if cell.is_some() {
+                return Some((x, y));
+            }
+
+            distance += 1;
+            x += dx;
+            y += dy;
+        }
+
+        None
+    }
+
+    fn occupied_seats(&self) -> usize {
+        self.seats
+            .cells()
+            .filter(|seat| matches!(seat, Some(Seat::Occupied)))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day11 {
+        Day11::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_simulate() {
+        let mut seating_area = parse_sample(include_str!("../sample.txt")).seating_area;
+        seating_area.simulate();
+        assert_eq!(seating_area.occupied_seats(), 37);
+    }
+
+    #[test]
+    fn test_simulate_with_sight() {
+        let mut seating_area = parse_sample(include_str!("../sample.txt")).seating_area;
+        seating_area.simulate_with_sight();
+        assert_eq!(seating_area.occupied_seats(), 26);
+    }
+}
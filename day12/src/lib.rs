@@ -0,0 +1,185 @@
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day12 {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    fn parse(input: &Input) -> Result<Day12, ParseError> {
+        let parse_regex = Regex::new(r"^(\w)(\d+)$").unwrap();
+
+        let instructions = input
+            .numbered_lines()
+            .map(|line| {
+                line.parse(|text| {
+                    let captures = parse_regex
+                        .captures(text)
+                        .ok_or_else(|| ParseError::unexpected("an instruction like `F10`", text))?;
+                    let action = &captures[1];
+                    let amount: isize = number(&captures[2], 2)?;
+
+                    if (action == "L" || action == "R") && amount % 90 != 0 {
+                        return Err(ParseError::unexpected(
+                            "a multiple of 90 degrees",
+                            &captures[2],
+                        )
+                        .at_column(2));
+                    }
+
+                    match action {
+                        "N" => Ok(Instruction::North(amount)),
+                        "S" => Ok(Instruction::South(amount)),
+                        "E" => Ok(Instruction::East(amount)),
+                        "W" => Ok(Instruction::West(amount)),
+                        "L" => Ok(Instruction::Left(amount)),
+                        "R" => Ok(Instruction::Right(amount)),
+                        "F" => Ok(Instruction::Forward(amount)),
+                        other => Err(ParseError::unexpected(
+                            "one of `N`, `S`, `E`, `W`, `L`, `R` or `F`",
+                            other,
+                        )),
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day12 { instructions })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut vertical: isize = 0;
+        let mut horizontal: isize = 0;
+        let mut orientation = Orientation::east();
+
+        self.instructions.iter().for_each(|instruction| {
+            match instruction {
+                Instruction::North(amount) => vertical += amount,
+                Instruction::South(amount) => vertical -= amount,
+                Instruction::East(amount) => horizontal += amount,
+                Instruction::West(amount) => horizontal -= amount,
+                Instruction::Left(amount) => orientation.rotate(-amount),
+                Instruction::Right(amount) => orientation.rotate(*amount),
+                Instruction::Forward(amount) => match orientation.direction() {
+                    Direction::North => vertical += amount,
+                    Direction::South => vertical -= amount,
+                    Direction::East => horizontal += amount,
+                    Direction::West => horizontal -= amount,
+                },
+            }
+
+            trace!("moved", east = horizontal, north = vertical);
+        });
+
+        (vertical.abs() + horizontal.abs()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut vertical: isize = 0;
+        let mut horizontal: isize = 0;
+        let mut waypoint_x: isize = 10;
+        let mut waypoint_y: isize = 1;
+
+        self.instructions.iter().for_each(|instruction| {
+            match instruction {
+                Instruction::North(amount) => waypoint_y += amount,
+                Instruction::South(amount) => waypoint_y -= amount,
+                Instruction::East(amount) => waypoint_x += amount,
+                Instruction::West(amount) => waypoint_x -= amount,
+                Instruction::Left(amount) => {
+                    let (x, y) = rotate(waypoint_x, waypoint_y, -amount);
+                    waypoint_x = x;
+                    waypoint_y = y;
+                }
+                Instruction::Right(amount) => {
+                    let (x, y) = rotate(waypoint_x, waypoint_y, *amount);
+                    waypoint_x = x;
+                    waypoint_y = y;
+                }
+                Instruction::Forward(amount) => {
+                    horizontal += waypoint_x * amount;
+                    vertical += waypoint_y * amount;
+                }
+            }
+
+            trace!(
+                "moved",
+                east = horizontal,
+                north = vertical,
+                waypoint_east = waypoint_x,
+                waypoint_north = waypoint_y,
+            );
+        });
+
+        (vertical.abs() + horizontal.abs()).into()
+    }
+}
+
+fn rotate(x: isize, y: isize, degrees: isize) -> (isize, isize) {
+    let rotation = ((degrees / 90) + 4) % 4;
+
+    match rotation {
+        0 => (x, y),
+        1 => (y, -x),
+        2 => (-x, -y),
+        3 => (-y, x),
+        _ => unreachable!(),
+    }
+}
+
+enum Instruction {
+    North(isize),
+    South(isize),
+    East(isize),
+    West(isize),
+    Left(isize),
+    Right(isize),
+    Forward(isize),
+}
+
+enum Direction {
+    North,
+    South,
+    West,
+    East,
+}
+
+struct Orientation(u8);
+
+impl Orientation {
+    fn east() -> Orientation {
+        Orientation(0)
+    }
+
+    fn direction(&self) -> Direction {
+        match self.0 {
+            0 => Direction::East,
+            1 => Direction::South,
+            2 => Direction::West,
+            3 => Direction::North,
+            _ => unreachable!(),
+        }
+    }
+
+    fn rotate(&mut self, degrees: isize) {
+        self.0 = ((self.0 as isize + degrees / 90 + 4) % 4).unsigned_abs() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day12 {
+        Day12::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(25));
+        assert_eq!(day.part2(), Answer::from(286));
+    }
+}
@@ -0,0 +1,166 @@
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day13 {
+    starting_time: usize,
+    bus_ids: Vec<Option<usize>>,
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    fn parse(input: &Input) -> Result<Day13, ParseError> {
+        let mut lines = input.numbered_lines();
+
+        let starting_time: usize = lines
+            .next()
+            .ok_or_else(|| input.end().missing("the earliest departure time"))?
+            .parse(|text| number(text, 1))?;
+        let bus_line = lines
+            .next()
+            .ok_or_else(|| input.end().missing("the list of bus ids"))?;
+        let bus_ids: Vec<Option<usize>> = bus_line.parse(|text| {
+            let mut column = 1;
+
+            text.split(',')
+                .map(|timestamp| {
+                    let bus_id = match timestamp {
+                        "x" => Ok(None),
+                        id => match number(id, column)? {
+                            0 => {
+                                Err(ParseError::unexpected("a bus id or `x`", id).at_column(column))
+                            }
+                            id => Ok(Some(id)),
+                        },
+                    };
+
+                    column += timestamp.chars().count() + 1;
+                    bus_id
+                })
+                .collect()
+        })?;
+        if bus_ids.iter().all(Option::is_none) {
+            return Err(bus_line.missing("a bus id"));
+        }
+
+        Ok(Day13 {
+            starting_time,
+            bus_ids,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let (next_bus_id, next_bus_minutes) = self
+            .bus_ids
+            .iter()
+            .filter_map(|timestamp| timestamp.as_ref())
+            .map(|timestamp| {
+                let minutes = (timestamp - self.starting_time % timestamp) % timestamp;
+                trace!("bus_waited_for", bus = timestamp, minutes = minutes);
+                (timestamp, minutes)
+            })
+            .min_by_key(|(_, minutes)| *minutes)
+            .unwrap();
+
+        (next_bus_id * next_bus_minutes).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut buses = self.bus_ids.iter().enumerate();
+
+        // Checked manually on the input file that the first in the list is an actual id and not an 'x'
+        let (_, first_id) = buses.next().unwrap();
+        let first_id = first_id.unwrap();
+
+        let (part_2, _) = buses
+            .filter_map(|(index, timestamp)| timestamp.as_ref().map(|timestamp| (index, timestamp)))
+            .fold(
+                (first_id, first_id),
+                |(previous_result, previous_diff), (offset, id)| {
+                    let next_multiple = find_closest_with_remainder(
+                        previous_result,
+                        previous_diff,
+                        *id,
+                        (id - offset % id) % id,
+                    );
+
+                    // Every bus so far keeps leaving at the right offset every `period` minutes
+                    trace!(
+                        "bus_aligned",
+                        bus = id,
+                        offset = offset,
+                        timestamp = next_multiple,
+                        period = previous_diff * id,
+                    );
+                    (next_multiple, previous_diff * id)
+                },
+            );
+
+        part_2.into()
+    }
+}
+
+/// Finds the closest number starting with `base` that, adding multiples of `diff`,
+/// has a reminder of `expected_reminder` when divided by `divisor`.
+fn find_closest_with_remainder(
+    base: usize,
+    diff: usize,
+    divisor: usize,
+    expected_remainder: usize,
+) -> usize {
+    let mut result = base;
+
+    while result % divisor != expected_remainder {
+        result += diff;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day13 {
+        Day13::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(295));
+        assert_eq!(day.part2(), Answer::from(1068781));
+    }
+
+    #[test]
+    fn test_bus_leaving_right_away() {
+        let day = parse_sample("939\n7,13,59,31,19\n");
+        assert_eq!(day.part1(), Answer::from(295));
+        let day = parse_sample("949\n7,13,59,31,73\n");
+        assert_eq!(day.part1(), Answer::from(0));
+    }
+
+    #[test]
+    fn test_no_bus_ids() {
+        let error = Day13::parse(&Input::from("939\nx,x\n")).err().unwrap();
+        assert_eq!(error.to_string(), "missing a bus id at line 2, column 4");
+    }
+
+    #[test]
+    fn test_earliest_timestamp() {
+        // Only the bus ids matter for part 2
+        let examples = [
+            ("17,x,13,19", 3417),
+            ("67,7,59,61", 754018),
+            ("67,x,7,59,61", 779210),
+            ("67,7,x,59,61", 1261476),
+            ("1789,37,47,1889", 1202161486),
+            // Bus 7 leaves 7 minutes after the first one, at the same time as it does
+            ("3,x,x,x,x,x,x,7", 21),
+        ];
+
+        for (bus_ids, timestamp) in &examples {
+            let day = parse_sample(&format!("0\n{}", bus_ids));
+            assert_eq!(day.part2(), Answer::from(*timestamp));
+        }
+    }
+}
@@ -0,0 +1,306 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+use regex::Regex;
+
+pub struct Day14 {
+    code: Vec<Instruction>,
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    fn parse(input: &Input) -> Result<Day14, ParseError> {
+        let code = input
+            .numbered_lines()
+            .map(|line| line.parse(str::parse))
+            .collect::<Result<_, _>>()?;
+        Ok(Day14 { code })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut computer = Computer::new();
+        computer.run(self.code.clone());
+        computer.memory_sum().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut computer = Computer::new();
+        computer.run_v2(self.code.clone());
+        computer.memory_sum().into()
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum Bit {
+    Zero,
+    One,
+    Float,
+}
+
+#[derive(PartialEq, Debug, Default, Clone)]
+struct Mask(Vec<Bit>);
+
+impl Mask {
+    fn new(mask: Vec<Bit>) -> Mask {
+        Mask(mask)
+    }
+
+    fn mask_value(&self, value: u64) -> u64 {
+        let mut new_value = value;
+        let mask_length = self.0.len();
+
+        for (i, m) in self.0.iter().enumerate() {
+            new_value = match m {
+                Bit::One => new_value | (1 << (mask_length - 1 - i)),
+                Bit::Zero => {
+                    let mask: u64 = !1;
+                    let mask = mask.rotate_left((mask_length - 1 - i) as u32);
+                    new_value & mask
+                }
+                _ => new_value,
+            }
+        }
+
+        new_value
+    }
+
+    fn mask_address(&self, address: u64) -> u64 {
+        let mut new_address = address;
+        let mask_length = self.0.len();
+
+        for (i, m) in self.0.iter().enumerate() {
+            new_address = match m {
+                Bit::One => new_address | (1 << (mask_length - 1 - i)),
+                _ => new_address,
+            }
+        }
+
+        new_address
+    }
+
+    fn floating_addresses(&self, base_address: u64) -> Vec<u64> {
+        let float_indexes: Vec<u8> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, bit)| match bit {
+                Bit::Float => Some((self.0.len() - 1 - index) as u8),
+                _ => None,
+            })
+            .collect();
+
+        let base_address = self.mask_address(base_address);
+
+        (0..2_u64.pow(float_indexes.len() as u32))
+            .map(|i| {
+                let mut address = base_address;
+
+                for (bit_index, float_index) in float_indexes.iter().enumerate() {
+                    if i & (1 << bit_index) != 0 {
+                        address |= 1 << float_index;
+                    } else {
+                        let mask: u64 = !1;
+                        let mask = mask.rotate_left(*float_index as u32);
+                        address &= mask;
+                    }
+                }
+
+                address
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Mask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Masks are applied to 36-bit values, anything longer would overflow the shifts
+        if let Some((i, _)) = s.char_indices().nth(36) {
+            return Err(ParseError::unexpected("a mask of up to 36 bits", &s[i..]).at_column(37));
+        }
+
+        let bits: Result<Vec<Bit>, _> =
+            s.chars()
+                .enumerate()
+                .map(|(i, c)| match c {
+                    '0' => Ok(Bit::Zero),
+                    '1' => Ok(Bit::One),
+                    'X' => Ok(Bit::Float),
+                    other => Err(ParseError::unexpected("`0`, `1` or `X`", other.to_string())
+                        .at_column(i + 1)),
+                })
+                .collect();
+
+        bits.map(Mask::new)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+enum Instruction {
+    SetMask(Mask),
+    SetMemory(u64, u64),
+}
+
+lazy_static! {
+    static ref MASK_REGEX: Regex = Regex::new(r"^mask = (.+)$").expect("Failed to compile regex");
+    static ref MEMORY_REGEX: Regex =
+        Regex::new(r"^mem\[(\d+)\] = (\d+)$").expect("Failed to compile regex");
+}
+
+impl Instruction {
+    fn parse_mask(code: &str) -> Result<Instruction, ParseError> {
+        let captures = MASK_REGEX
+            .captures(code)
+            .ok_or_else(|| ParseError::unexpected("a mask like `mask = X1X0`", code))?;
+        let mask_value = captures.get(1).unwrap();
+
+        mask_value
+            .as_str()
+            .parse()
+            .map(Instruction::SetMask)
+            .map_err(|error: ParseError| error.offset(mask_value.start()))
+    }
+
+    fn parse_memory_assignment(code: &str) -> Result<Instruction, ParseError> {
+        let captures = MEMORY_REGEX.captures(code).ok_or_else(|| {
+            ParseError::unexpected("a memory assignment like `mem[8] = 11`", code)
+        })?;
+        let address = captures.get(1).unwrap();
+        let value = captures.get(2).unwrap();
+        let memory_address: u64 = number(address.as_str(), address.start() + 1)?;
+        let memory_value: u64 = number(value.as_str(), value.start() + 1)?;
+
+        Ok(Instruction::SetMemory(memory_address, memory_value))
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("mask") {
+            Instruction::parse_mask(s)
+        } else if s.starts_with("mem") {
+            Instruction::parse_memory_assignment(s)
+        } else {
+            Err(ParseError::unexpected("`mask` or `mem`", s))
+        }
+    }
+}
+
+struct Computer {
+    mask: Mask,
+    memory: HashMap<u64, u64>,
+}
+
+impl Computer {
+    fn new() -> Computer {
+        Computer {
+            mask: Mask::default(),
+            memory: HashMap::new(),
+        }
+    }
+
+    fn run(&mut self, code: Vec<Instruction>) {
+        self.memory.clear();
+
+        for instruction in code {
+            match instruction {
+                Instruction::SetMask(mask) => self.mask = mask,
+                Instruction::SetMemory(address, value) => self.set_memory(address, value),
+            }
+        }
+    }
+
+    fn run_v2(&mut self, code: Vec<Instruction>) {
+        self.memory.clear();
+
+        for instruction in code {
+            match instruction {
+                Instruction::SetMask(mask) => self.mask = mask,
+                Instruction::SetMemory(base_address, value) => {
+                    self.set_floating_memory(base_address, value)
+                }
+            }
+        }
+    }
+
+    fn set_memory(&mut self, address: u64, value: u64) {
+        let masked = self.mask.mask_value(value);
+        trace!(
+            "memory_written",
+            address = address,
+            value = value,
+            masked = masked
+        );
+        self.memory.insert(address, masked);
+    }
+
+    fn memory_sum(&self) -> u64 {
+        self.memory.values().copied().sum()
+    }
+
+    fn set_floating_memory(&mut self, base_address: u64, value: u64) {
+        let addresses = self.mask.floating_addresses(base_address);
+        trace!(
+            "memory_written",
+            address = base_address,
+            value = value,
+            addresses = addresses.len()
+        );
+
+        for address in addresses {
+            self.memory.insert(address, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mask() {
+        let instruction = Instruction::parse_mask("mask = 0X1X10").unwrap();
+        assert_eq!(instruction, Instruction::SetMask("0X1X10".parse().unwrap()));
+    }
+
+    #[test]
+    fn parse_invalid_mask() {
+        let error = Instruction::parse_mask("mask = 0X1Y10").unwrap_err();
+        assert_eq!(error.column, 11);
+    }
+
+    #[test]
+    fn parse_memory_assignment() {
+        let instruction = Instruction::parse_memory_assignment("mem[123] = 456").unwrap();
+        assert_eq!(instruction, Instruction::SetMemory(123, 456));
+    }
+
+    #[test]
+    fn test_mask_value() {
+        let mask: Mask = "XX0011".parse().unwrap();
+        assert_eq!(mask.mask_value(0b101010,), 0b100011);
+    }
+
+    fn parse_sample(sample: &str) -> Day14 {
+        Day14::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(165));
+    }
+
+    #[test]
+    fn test_sample_part2() {
+        let day = parse_sample(include_str!("../sample2.txt"));
+        assert_eq!(day.part2(), Answer::from(208));
+    }
+}
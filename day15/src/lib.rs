@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day15 {
+    numbers: Vec<usize>,
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    fn parse(input: &Input) -> Result<Day15, ParseError> {
+        let line = input
+            .numbered_lines()
+            .find(|line| !line.trim().is_empty())
+            .ok_or_else(|| input.end().missing("the starting numbers"))?;
+
+        let numbers = line.parse(|text| {
+            let mut column = 1;
+
+            text.split(',')
+                .map(|n| {
+                    let leading = n.len() - n.trim_start().len();
+                    let number = number(n.trim(), column + leading);
+                    column += n.chars().count() + 1;
+                    number
+                })
+                .collect()
+        })?;
+
+        Ok(Day15 { numbers })
+    }
+
+    fn part1(&self) -> Answer {
+        solve(&self.numbers, 2020).into()
+    }
+
+    fn part2(&self) -> Answer {
+        solve(&self.numbers, 30_000_000).into()
+    }
+}
+
+fn solve(numbers: &[usize], goal: usize) -> usize {
+    let mut last_index: HashMap<usize, usize> = HashMap::new();
+
+    let (last_number, predefined_numbers) = numbers.split_last().unwrap();
+    let mut last_number = *last_number;
+    let mut i = 0;
+
+    for n in predefined_numbers {
+        last_index.insert(*n, i);
+        i += 1;
+    }
+
+    i += 1;
+
+    while i < goal {
+        let next_number = if let Some(index) = last_index.get(&last_number) {
+            i - 1 - index
+        } else {
+            0
+        };
+
+        last_index.insert(last_number, i - 1);
+        i += 1;
+        last_number = next_number;
+    }
+
+    trace!(
+        "game_finished",
+        turns = goal,
+        distinct_numbers = last_index.len() + 1
+    );
+    last_number
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day15 {
+        Day15::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(436));
+        assert_eq!(day.part2(), Answer::from(175594));
+    }
+
+    #[test]
+    fn test_2020th_number() {
+        assert_eq!(solve(&[1, 3, 2], 2020), 1);
+        assert_eq!(solve(&[2, 1, 3], 2020), 10);
+        assert_eq!(solve(&[1, 2, 3], 2020), 27);
+        assert_eq!(solve(&[2, 3, 1], 2020), 78);
+        assert_eq!(solve(&[3, 2, 1], 2020), 438);
+        assert_eq!(solve(&[3, 1, 2], 2020), 1836);
+    }
+}
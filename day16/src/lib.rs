@@ -0,0 +1,345 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use aoc::{
+    parse::{column, number},
+    trace, Answer, Input, Line, ParseError, Solution,
+};
+use regex::Regex;
+
+pub struct Day16 {
+    rules: Vec<FieldRule>,
+    my_ticket: Ticket,
+    tickets: Vec<Ticket>,
+}
+
+impl Day16 {
+    // Works out which position of the tickets holds each field, unless
+    // elimination can't tell: some field fits no position, or a round rules
+    // nothing out, like when two fields fit the same positions
+    fn field_positions(&self) -> Option<HashMap<&str, usize>> {
+        let rules = &self.rules;
+        let valid_tickets: Vec<Ticket> = self
+            .tickets
+            .iter()
+            .filter(|ticket| !ticket.is_invalid(rules))
+            .cloned()
+            .collect();
+
+        let mut used_fields: HashSet<usize> = HashSet::new();
+
+        let mut available_fields: HashMap<&FieldRule, HashSet<usize>> = rules
+            .iter()
+            .map(|field| (field, field.valid_fields(&valid_tickets)))
+            .collect();
+        let mut round = 0;
+
+        trace!(
+            "tickets_filtered",
+            valid = valid_tickets.len(),
+            invalid = self.tickets.len() - valid_tickets.len(),
+        );
+
+        while available_fields.iter().any(|(_, set)| set.len() > 1) {
+            let candidates: usize = available_fields.values().map(HashSet::len).sum();
+            let decided = used_fields.len();
+            round += 1;
+            trace!(
+                "elimination_round",
+                round = round,
+                undecided = available_fields
+                    .values()
+                    .filter(|set| set.len() > 1)
+                    .count(),
+            );
+
+            available_fields
+                .iter_mut()
+                .filter(|(_, set)| set.len() > 1)
+                .for_each(|(_, set)| {
+                    for used in &used_fields {
+                        set.remove(used);
+                    }
+                });
+
+            for (field, set) in &available_fields {
+                if set.len() == 1 {
+                    for position in set {
+                        if used_fields.insert(*position) {
+                            trace!(
+                                "field_found",
+                                round = round,
+                                field = field.name,
+                                position = position,
+                            );
+                        }
+                    }
+                }
+            }
+
+            let remaining: usize = available_fields.values().map(HashSet::len).sum();
+            if remaining == candidates && used_fields.len() == decided {
+                trace!("elimination_stuck", round = round);
+                return None;
+            }
+        }
+
+        available_fields
+            .iter()
+            .map(|(field, position)| Some((field.name.as_str(), *position.iter().next()?)))
+            .collect()
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    fn parse(input: &Input) -> Result<Day16, ParseError> {
+        let mut groups = input.numbered_groups();
+
+        let rules: Vec<FieldRule> = groups
+            .next()
+            .ok_or_else(|| input.end().missing("the field rules"))?
+            .iter()
+            .map(|line| line.parse(str::parse))
+            .collect::<Result<_, _>>()?;
+
+        let my_ticket = groups
+            .next()
+            .ok_or_else(|| input.end().missing("`your ticket:`"))?;
+        if let Some(extra) = my_ticket.get(2) {
+            return Err(
+                ParseError::unexpected("a blank line after your ticket", extra.text)
+                    .on_line(extra.number),
+            );
+        }
+        let my_ticket = parse_tickets(&my_ticket, "your ticket:", rules.len())?
+            .pop()
+            .ok_or_else(|| ParseError::missing("your ticket").on_line(my_ticket[0].number + 1))?;
+
+        let tickets = groups
+            .next()
+            .ok_or_else(|| input.end().missing("`nearby tickets:`"))?;
+        let tickets = parse_tickets(&tickets, "nearby tickets:", rules.len())?;
+
+        Ok(Day16 {
+            rules,
+            my_ticket,
+            tickets,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        self.tickets
+            .iter()
+            .filter(|ticket| ticket.is_invalid(&self.rules))
+            .flat_map(|ticket| {
+                let invalid_fields = ticket.invalid_fields(&self.rules);
+                trace!(
+                    "ticket_rejected",
+                    invalid_values = format!("{:?}", invalid_fields)
+                );
+                invalid_fields
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        let field_positions = match self.field_positions() {
+            Some(field_positions) => field_positions,
+            None => return Answer::None,
+        };
+        let departure_indexes: Vec<usize> = field_positions
+            .iter()
+            .filter(|(name, _)| name.starts_with("departure"))
+            .map(|(_, position)| *position)
+            .collect();
+
+        self.my_ticket
+            .fields
+            .iter()
+            .enumerate()
+            .filter_map(|(i, n)| {
+                if departure_indexes.contains(&i) {
+                    Some(n)
+                } else {
+                    None
+                }
+            })
+            .product::<usize>()
+            .into()
+    }
+}
+
+/// Parses a group of tickets introduced by `header`, each with a value for every rule.
+fn parse_tickets(lines: &[Line], header: &str, fields: usize) -> Result<Vec<Ticket>, ParseError> {
+    let (first, tickets) = lines.split_first().unwrap();
+    if first.text != header {
+        return Err(
+            ParseError::unexpected(format!("`{}`", header), first.text).on_line(first.number)
+        );
+    }
+
+    tickets
+        .iter()
+        .map(|line| {
+            let ticket: Ticket = line.parse(str::parse)?;
+
+            if ticket.fields.len() != fields {
+                return Err(ParseError::unexpected(
+                    format!("a ticket with {} fields, one per rule", fields),
+                    line.text,
+                )
+                .on_line(line.number));
+            }
+
+            Ok(ticket)
+        })
+        .collect()
+}
+
+#[derive(Clone)]
+struct Ticket {
+    fields: Vec<usize>,
+}
+
+impl Ticket {
+    fn new(fields: Vec<usize>) -> Ticket {
+        Ticket { fields }
+    }
+
+    fn is_invalid(&self, rules: &[FieldRule]) -> bool {
+        self.fields
+            .iter()
+            .any(|value| !rules.iter().any(|rule| rule.check(*value)))
+    }
+
+    fn invalid_fields(&self, rules: &[FieldRule]) -> Vec<usize> {
+        self.fields
+            .iter()
+            .filter(|value| !rules.iter().any(|rule| rule.check(**value)))
+            .copied()
+            .collect()
+    }
+}
+
+impl FromStr for Ticket {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut column = 1;
+
+        s.split(',')
+            .map(|n| {
+                let value = number(n, column);
+                column += n.chars().count() + 1;
+                value
+            })
+            .collect::<Result<Vec<usize>, _>>()
+            .map(Ticket::new)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct FieldRule {
+    name: String,
+    first_range: RangeInclusive<usize>,
+    second_range: RangeInclusive<usize>,
+}
+
+impl FieldRule {
+    fn check(&self, value: usize) -> bool {
+        self.first_range.contains(&value) || self.second_range.contains(&value)
+    }
+
+    fn valid_fields(&self, tickets: &[Ticket]) -> HashSet<usize> {
+        let mut result = HashSet::new();
+
+        for i in 0..tickets.first().unwrap().fields.len() {
+            if tickets.iter().all(|ticket| self.check(ticket.fields[i])) {
+                result.insert(i);
+            }
+        }
+
+        result
+    }
+}
+
+lazy_static! {
+    // Manually checked the input file to confirm that all field rules look like this
+    static ref RANGE_REGEX: Regex = Regex::new(r"^(.+?): (\d+)-(\d+) or (\d+)-(\d+)$").expect("Failed to compile regex");
+}
+
+impl FromStr for FieldRule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = RANGE_REGEX
+            .captures(s)
+            .ok_or_else(|| ParseError::unexpected("a field rule like `class: 1-3 or 5-7`", s))?;
+        let bound = |i| {
+            let bound = captures.get(i).unwrap();
+            number(bound.as_str(), column(s, bound.start()))
+        };
+
+        let name: String = captures[1].into();
+        let first_from: usize = bound(2)?;
+        let first_to: usize = bound(3)?;
+        let second_from: usize = bound(4)?;
+        let second_to: usize = bound(5)?;
+
+        Ok(FieldRule {
+            name,
+            first_range: (first_from..=first_to),
+            second_range: (second_from..=second_to),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day16 {
+        Day16::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(71));
+    }
+
+    #[test]
+    fn test_field_positions() {
+        let day = parse_sample(include_str!("../sample2.txt"));
+        let positions = day.field_positions().unwrap();
+
+        assert_eq!(positions["row"], 0);
+        assert_eq!(positions["class"], 1);
+        assert_eq!(positions["seat"], 2);
+    }
+
+    #[test]
+    fn test_ambiguous_fields() {
+        let day = parse_sample(
+            "a: 1-5 or 7-9\n\
+             b: 1-5 or 7-9\n\
+             \n\
+             your ticket:\n\
+             1,7\n\
+             \n\
+             nearby tickets:\n\
+             3,8\n",
+        );
+        assert_eq!(day.field_positions(), None);
+        assert_eq!(day.part2(), Answer::None);
+    }
+}
@@ -0,0 +1,169 @@
+use std::io;
+
+use aoc::{
+    automaton::Lattice, trace, Answer, Automaton, Canvas, Color, Frame, Grid, Input, ParseError,
+    Part, Point, Rule, Solution, SparseGrid, Visualize,
+};
+
+pub struct Day17 {
+    initial_slice: Grid<bool>,
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    fn parse(input: &Input) -> Result<Day17, ParseError> {
+        let initial_slice = Grid::parse(input.numbered_lines(), "`.` or `#`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Day17 { initial_slice })
+    }
+
+    fn part1(&self) -> Answer {
+        self.boot(self.initial_points(|x, y| [x, y, 0])).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.boot(self.initial_points(|x, y| [x, y, 0, 0])).into()
+    }
+}
+
+impl Day17 {
+    /// The active cubes of the initial slice, placed in a dimension by `point`.
+    fn initial_points<T: Point>(&self, point: impl Fn(isize, isize) -> T) -> SparseGrid<T> {
+        self.initial_slice
+            .iter()
+            .filter(|(_, active)| **active)
+            .map(|((x, y), _)| point(x as isize, y as isize))
+            .collect()
+    }
+
+    /// How many cubes are active after the boot process.
+    fn boot<T: Point>(&self, active_points: SparseGrid<T>) -> usize {
+        let mut dimension = pocket_dimension(active_points);
+
+        for cycle in 1..=CYCLES {
+            dimension.step();
+            trace!("cycle_done", cycle = cycle, active = dimension.live().len());
+        }
+
+        dimension.live().len()
+    }
+
+    /// Draws the boot process, placing points in four dimensions with `to_4d`.
+    fn draw_boot<T: Point>(
+        &self,
+        active_points: SparseGrid<T>,
+        to_4d: impl Fn(T) -> [isize; 4],
+        canvas: &mut dyn Canvas,
+    ) -> io::Result<()> {
+        let mut dimension = pocket_dimension(active_points);
+
+        for cycle in 0..=CYCLES {
+            if cycle > 0 {
+                dimension.step();
+            }
+            canvas.draw(&frame(dimension.live().points().map(&to_4d).collect()))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Visualize for Day17 {
+    /// The pocket dimension before and after each cycle.
+    fn visualize(&self, part: Part, canvas: &mut dyn Canvas) -> io::Result<()> {
+        match part {
+            Part::One => self.draw_boot(
+                self.initial_points(|x, y| [x, y, 0]),
+                |[x, y, z]| [x, y, z, 0],
+                canvas,
+            ),
+            Part::Two => self.draw_boot(
+                self.initial_points(|x, y| [x, y, 0, 0]),
+                |point| point,
+                canvas,
+            ),
+        }
+    }
+}
+
+/// How many cycles the boot process takes.
+const CYCLES: usize = 6;
+
+fn pocket_dimension<T: Point>(active_points: SparseGrid<T>) -> Automaton<T, Lattice> {
+    let rule = Rule {
+        birth: 3..=3,
+        survival: 2..=3,
+    };
+    Automaton::new(Lattice, rule, active_points)
+}
+
+/// Every `x`, `y` slice of the active cubes side by side, `z` growing to the
+/// right and `w` downwards. Active cubes are white, with grey between slices.
+fn frame(active_points: SparseGrid<[isize; 4]>) -> Frame {
+    let mut points = active_points.points();
+    let first = match points.next() {
+        Some(first) => first,
+        None => return Grid::from_rows(vec![]),
+    };
+
+    let (min, max) = points.fold((first, first), |(mut min, mut max), point| {
+        for axis in 0..4 {
+            min[axis] = min[axis].min(point[axis]);
+            max[axis] = max[axis].max(point[axis]);
+        }
+        (min, max)
+    });
+    let size = |axis: usize| (max[axis] - min[axis] + 1) as usize;
+    let (width, height) = (size(0), size(1));
+
+    let mut frame = Grid::new(
+        size(2) * (width + 1) - 1,
+        size(3) * (height + 1) - 1,
+        Color::GREY,
+    );
+
+    for z in 0..size(2) {
+        for w in 0..size(3) {
+            for x in 0..width {
+                for y in 0..height {
+                    let point = [
+                        min[0] + x as isize,
+                        min[1] + y as isize,
+                        min[2] + z as isize,
+                        min[3] + w as isize,
+                    ];
+
+                    frame[(z * (width + 1) + x, w * (height + 1) + y)] =
+                        if active_points.contains(point) {
+                            Color::WHITE
+                        } else {
+                            Color::BLACK
+                        };
+                }
+            }
+        }
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day17 {
+        Day17::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(112));
+        assert_eq!(day.part2(), Answer::from(848));
+    }
+}
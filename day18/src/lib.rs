@@ -0,0 +1,305 @@
+use std::iter::Peekable;
+
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day18 {
+    operations: Vec<Vec<Token>>,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    fn parse(input: &Input) -> Result<Day18, ParseError> {
+        let operations = input
+            .numbered_lines()
+            .map(|line| line.parse(parse))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day18 { operations })
+    }
+
+    fn part1(&self) -> Answer {
+        self.operations
+            .iter()
+            .enumerate()
+            .map(|(i, operation)| {
+                let value = run(operation);
+                trace!("expression_evaluated", line = i + 1, value = value);
+                value
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.operations
+            .iter()
+            .enumerate()
+            .map(|(i, operation)| {
+                let value = run_with_precedence(operation);
+                trace!("expression_evaluated", line = i + 1, value = value);
+                value
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Token {
+    Number(usize),
+    Plus,
+    Star,
+    LeftParen,
+    RightParen,
+}
+
+// Besides splitting the expression into tokens, checks that numbers and operators
+// alternate and parentheses are balanced, which both ways of running it rely on.
+fn parse(expr: &str) -> Result<Vec<Token>, ParseError> {
+    let mut result = vec![];
+    let mut chars = expr.chars().enumerate().peekable();
+    let mut open_parens = 0;
+
+    while let Some((i, c)) = chars.next() {
+        let expects_operand = matches!(
+            result.last(),
+            None | Some(Token::Plus) | Some(Token::Star) | Some(Token::LeftParen)
+        );
+
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' if !expects_operand => Token::Plus,
+            '*' if !expects_operand => Token::Star,
+            '(' if expects_operand => {
+                open_parens += 1;
+                Token::LeftParen
+            }
+            ')' if !expects_operand && open_parens > 0 => {
+                open_parens -= 1;
+                Token::RightParen
+            }
+            n if n.is_ascii_digit() && expects_operand => {
+                let mut number_text = vec![n];
+
+                while chars.peek().is_some_and(|(_, c)| c.is_ascii_digit()) {
+                    number_text.push(chars.next().unwrap().1);
+                }
+
+                let number_text: String = number_text.iter().copied().collect();
+                Token::Number(number(&number_text, i + 1)?)
+            }
+            other => {
+                let expected = match (expects_operand, open_parens) {
+                    (true, _) => "a number or `(`",
+                    (false, 0) => "`+` or `*`",
+                    (false, _) => "`+`, `*` or `)`",
+                };
+
+                return Err(ParseError::unexpected(expected, other.to_string()).at_column(i + 1));
+            }
+        };
+
+        result.push(token);
+    }
+
+    let end = expr.chars().count() + 1;
+    if matches!(
+        result.last(),
+        None | Some(Token::Plus) | Some(Token::Star) | Some(Token::LeftParen)
+    ) {
+        Err(ParseError::missing("a number or `(`").at_column(end))
+    } else if open_parens > 0 {
+        Err(ParseError::missing("`)`").at_column(end))
+    } else {
+        Ok(result)
+    }
+}
+
+fn run(tokens: &[Token]) -> usize {
+    run_recursive(&mut tokens.iter())
+}
+
+fn run_recursive<'a>(tokens: &mut impl Iterator<Item = &'a Token>) -> usize {
+    let mut result = 0;
+    let mut last_operator = None;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Plus | Token::Star => last_operator = Some(token),
+            Token::Number(n) => match last_operator {
+                Some(Token::Plus) => result += n,
+                Some(Token::Star) => result *= n,
+                None => result = *n,
+                _ => unreachable!(),
+            },
+            Token::LeftParen => {
+                let inner_result = run_recursive(tokens);
+
+                match last_operator {
+                    Some(Token::Plus) => result += inner_result,
+                    Some(Token::Star) => result *= inner_result,
+                    None => result = inner_result,
+                    _ => unreachable!(),
+                }
+            }
+            Token::RightParen => return result,
+        }
+    }
+
+    result
+}
+
+#[derive(PartialEq, PartialOrd, Debug)]
+enum Precedence {
+    None,
+    Multiplication,
+    Addition,
+}
+
+fn run_with_precedence(tokens: &[Token]) -> usize {
+    let mut instructions: Vec<Token> = vec![];
+    let mut tokens = tokens.iter().peekable();
+
+    parse_tokens(&mut tokens, &mut instructions, Precedence::None);
+
+    let mut stack: Vec<usize> = vec![];
+
+    for instruction in instructions {
+        match instruction {
+            Token::Number(n) => stack.push(n),
+            Token::Plus => {
+                let b = stack.pop().unwrap();
+                let a = stack.pop().unwrap();
+                stack.push(a + b);
+            }
+            Token::Star => {
+                let b = stack.pop().unwrap();
+                let a = stack.pop().unwrap();
+                stack.push(a * b);
+            }
+            _ => unreachable!(), // Parenthesis don't exist at this point
+        }
+    }
+
+    stack.pop().unwrap()
+}
+
+// For such simple expressions we can reuse the tokens as instructions
+type Instruction = Token;
+
+fn parse_tokens<'a>(
+    tokens: &mut Peekable<impl Iterator<Item = &'a Token>>,
+    instructions: &mut Vec<Instruction>,
+    precedence: Precedence,
+) {
+    while let Some(token) = tokens.peek() {
+        match token {
+            Token::LeftParen => {
+                tokens.next();
+                parse_tokens(tokens, instructions, Precedence::None);
+                tokens.next(); // Consume right paren
+            }
+            Token::Number(_) => {
+                let number = tokens.next().unwrap();
+                instructions.push(*number);
+            }
+            other => panic!("Unexpected token {:?}", other),
+        }
+
+        while let Some(token) = tokens.peek() {
+            match token {
+                Token::Plus if precedence <= Precedence::Addition => {
+                    tokens.next();
+                    parse_tokens(tokens, instructions, Precedence::Addition);
+                    instructions.push(Token::Plus);
+                }
+                Token::Star if precedence <= Precedence::Multiplication => {
+                    tokens.next();
+                    parse_tokens(tokens, instructions, Precedence::Multiplication);
+                    instructions.push(Token::Star);
+                }
+                _ => return,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let tokens = parse("2 + (3 * 4)").unwrap();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Number(2),
+                Token::Plus,
+                Token::LeftParen,
+                Token::Number(3),
+                Token::Star,
+                Token::Number(4),
+                Token::RightParen
+            ]
+        )
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("2 + + 3").unwrap_err();
+        assert_eq!(error.column, 5);
+
+        let error = parse("2 + (3 * 4").unwrap_err();
+        assert_eq!(error.to_string(), "missing `)` at line 1, column 11");
+
+        let error = parse("2 + 3)").unwrap_err();
+        assert_eq!(error.column, 6);
+
+        let error = parse("2 - 3").unwrap_err();
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(3, run(&parse("1 + 2").unwrap()));
+        assert_eq!(9, run(&parse("1 + 2 * 3").unwrap()));
+        assert_eq!(7, run(&parse("1 + (2 * 3)").unwrap()));
+        assert_eq!(9, run(&parse("(1 + 2) * 3").unwrap()));
+        assert_eq!(51, run(&parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap()));
+    }
+
+    #[test]
+    fn test_run_with_precedence() {
+        assert_eq!(132, run_with_precedence(&parse("(9 * 8 + 6) + 6").unwrap()));
+        assert_eq!(
+            51,
+            run_with_precedence(&parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap())
+        );
+        assert_eq!(46, run_with_precedence(&parse("2 * 3 + (4 * 5)").unwrap()));
+        assert_eq!(
+            1445,
+            run_with_precedence(&parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap())
+        );
+        assert_eq!(
+            669060,
+            run_with_precedence(&parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap())
+        );
+        assert_eq!(
+            23340,
+            run_with_precedence(&parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap())
+        );
+    }
+
+    fn parse_sample(sample: &str) -> Day18 {
+        Day18::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(26457));
+        assert_eq!(day.part2(), Answer::from(694173));
+    }
+}
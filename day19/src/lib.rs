@@ -0,0 +1,415 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::str::FromStr;
+
+use aoc::{
+    parse::{column, number, words},
+    trace, Answer, Input, Line, ParseError, Solution,
+};
+use regex::Regex;
+
+pub struct Day19 {
+    ruleset: RuleSet,
+    messages: Vec<String>,
+}
+
+impl Day19 {
+    fn count_valid(&self, ruleset: &RuleSet) -> usize {
+        let regex = ruleset.regex();
+        trace!("regex_built", length = regex.as_str().len());
+
+        self.messages
+            .iter()
+            .filter(|message| {
+                let valid = regex.is_match(message);
+                trace!("message_checked", message = message, valid = valid);
+                valid
+            })
+            .count()
+    }
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    fn parse(input: &Input) -> Result<Day19, ParseError> {
+        let mut groups = input.numbered_groups();
+
+        let rule_definitions = groups.next().ok_or_else(|| input.end().missing("rule 0"))?;
+        let ruleset = RuleSet::parse(&rule_definitions)?;
+        let messages = groups
+            .next()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|line| line.text.into())
+            .collect();
+
+        Ok(Day19 { ruleset, messages })
+    }
+
+    fn part1(&self) -> Answer {
+        self.count_valid(&self.ruleset).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let longest_message = self.messages.iter().map(|message| message.len()).max();
+
+        let mut ruleset = self.ruleset.clone();
+        if !ruleset.unroll_loops(longest_message.unwrap_or(0)) {
+            return Answer::None;
+        }
+
+        self.count_valid(&ruleset).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RuleSet {
+    // Indexed by rule number, there can be gaps in the numbering
+    rules: Vec<Option<Rule>>,
+}
+
+// Rules are stored by number, so a number too large would take all the memory
+const MAX_RULE: usize = 9999;
+
+lazy_static! {
+    static ref RULE_DEFINITION: Regex =
+        Regex::new(r"^(\d+): (.+)$").expect("Failed to compile regex");
+    static ref FINAL_RULE: Regex = Regex::new("^\"(.)\"$").expect("Failed to compile regex");
+}
+
+impl RuleSet {
+    fn parse(lines: &[Line]) -> Result<RuleSet, ParseError> {
+        let rules: Vec<(usize, Rule, &Line)> = lines
+            .iter()
+            .map(|line| {
+                line.parse(|text| {
+                    let captures = RULE_DEFINITION.captures(text).ok_or_else(|| {
+                        ParseError::unexpected("a rule like `0: 1 2 | \"a\"`", text)
+                    })?;
+                    let index: usize = number(&captures[1], 1)?;
+                    if index > MAX_RULE {
+                        return Err(ParseError::unexpected(
+                            format!("a rule number up to {}", MAX_RULE),
+                            &captures[1],
+                        ));
+                    }
+                    let body = captures.get(2).unwrap();
+                    let rule: Rule = body
+                        .as_str()
+                        .parse()
+                        .map_err(|error: ParseError| error.offset(body.start()))?;
+                    Ok((index, rule, line))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut rule_lines: Vec<Option<&Line>> = vec![];
+        for (index, _, line) in &rules {
+            if rule_lines.len() <= *index {
+                rule_lines.resize(index + 1, None);
+            }
+
+            if rule_lines[*index].is_some() {
+                return Err(ParseError::unexpected(
+                    "a rule number not used before",
+                    index.to_string(),
+                )
+                .on_line(line.number));
+            }
+
+            rule_lines[*index] = Some(line);
+        }
+
+        let defined = |index: usize| rule_lines.get(index).is_some_and(Option::is_some);
+
+        if !defined(0) {
+            let after_rules = lines.last().map_or(1, |line| line.number + 1);
+            return Err(ParseError::missing("rule 0").on_line(after_rules));
+        }
+
+        for (_, _, line) in &rules {
+            let body_start = line.find(": ").unwrap() + 2;
+
+            for (word_column, word) in words(&line[body_start..]) {
+                if word.parse::<usize>().is_ok_and(|index| !defined(index)) {
+                    return Err(ParseError::unexpected("a defined rule", word)
+                        .on_line(line.number)
+                        .at_column(column(line, body_start) + word_column - 1));
+                }
+            }
+        }
+
+        let mut ruleset = RuleSet {
+            rules: vec![None; rule_lines.len()],
+        };
+        for (index, rule, _) in rules {
+            ruleset.rules[index] = Some(rule);
+        }
+
+        // Matching a rule that refers back to itself would never end
+        if let Some(index) = ruleset.find_loop() {
+            let line = rule_lines[index].unwrap();
+            return Err(ParseError::unexpected(
+                "a rule that doesn't lead back to itself",
+                line.text,
+            )
+            .on_line(line.number));
+        }
+
+        Ok(ruleset)
+    }
+
+    // A rule that refers back to itself, directly or through other rules, if any
+    fn find_loop(&self) -> Option<usize> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            Visiting,
+            Done,
+        }
+
+        let references = |index: usize| match &self.rules[index] {
+            Some(Rule::Composite(alternatives)) => alternatives.concat(),
+            _ => vec![],
+        };
+
+        let mut states = vec![State::New; self.rules.len()];
+
+        for start in 0..self.rules.len() {
+            if states[start] != State::New {
+                continue;
+            }
+
+            // Rules being visited, each with the rules it refers to still to visit
+            states[start] = State::Visiting;
+            let mut stack = vec![(start, references(start))];
+
+            while let Some((index, to_visit)) = stack.last_mut() {
+                let index = *index;
+
+                match to_visit.pop() {
+                    Some(next) => match states[next] {
+                        // `index` refers back to a rule leading to it
+                        State::Visiting => return Some(index),
+                        State::New => {
+                            states[next] = State::Visiting;
+                            stack.push((next, references(next)));
+                        }
+                        State::Done => {}
+                    },
+                    None => {
+                        states[index] = State::Done;
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn rule(&self, index: usize) -> &Rule {
+        self.rules[index].as_ref().unwrap()
+    }
+
+    fn regex(&self) -> Regex {
+        let regex_definition = self.rule(0).regex(&self.rules);
+        Regex::new(&format!("^{}$", regex_definition)).unwrap()
+    }
+
+    #[cfg(test)]
+    fn validate(&self, string_to_validate: &str) -> bool {
+        self.regex().is_match(string_to_validate)
+    }
+
+    // Part 2 turns rules 8 and 11 into `42 | 42 8` and `42 31 | 42 11 31`. A regex
+    // can't express that recursion, so instead both rules get as many repetitions
+    // as a message of `longest_message` characters could possibly need. Returns
+    // false, changing nothing, if there's no rule 8, 11, 42 or 31 to begin with.
+    fn unroll_loops(&mut self, longest_message: usize) -> bool {
+        if [8, 11, 42, 31]
+            .iter()
+            .any(|index| !matches!(self.rules.get(*index), Some(Some(_))))
+        {
+            return false;
+        }
+
+        let min_42 = self.min_length(42).max(1);
+        let min_31 = self.min_length(31).max(1);
+
+        self.rules[8] = Some(Rule::Composite(
+            (1..=longest_message / min_42)
+                .map(|n| vec![42; n])
+                .collect(),
+        ));
+
+        self.rules[11] = Some(Rule::Composite(
+            (1..=longest_message / (min_42 + min_31))
+                .map(|n| [vec![42; n], vec![31; n]].concat())
+                .collect(),
+        ));
+
+        true
+    }
+
+    // Length of the shortest string matched by the rule at `index`
+    fn min_length(&self, index: usize) -> usize {
+        match self.rule(index) {
+            Rule::Final(_) => 1,
+            Rule::Composite(alternatives) => alternatives
+                .iter()
+                .map(|sequence| sequence.iter().map(|rule| self.min_length(*rule)).sum())
+                .min()
+                .unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Rule {
+    Final(char),
+    Composite(Vec<Vec<usize>>),
+}
+
+impl Rule {
+    fn regex(&self, other_rules: &[Option<Rule>]) -> String {
+        match self {
+            Rule::Final(c) => c.to_string(),
+            Rule::Composite(rules) => {
+                let mut regex = String::from("(?:");
+
+                let subrules = rules
+                    .iter()
+                    .map(|rule| {
+                        rule.iter()
+                            .map(|rule_index| other_rules[*rule_index].as_ref().unwrap())
+                            .map(|rule| rule.regex(other_rules))
+                            .collect::<Vec<String>>()
+                            .join("")
+                    })
+                    .collect::<Vec<String>>()
+                    .join("|");
+                regex.push_str(&subrules);
+
+                regex.push(')');
+                regex
+            }
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(captures) = FINAL_RULE.captures(s) {
+            return Ok(Rule::Final(captures[1].chars().next().unwrap()));
+        }
+
+        let mut rules = vec![vec![]];
+
+        for (column, word) in words(s) {
+            if word == "|" {
+                rules.push(vec![]);
+            } else {
+                let index = number(word, column).map_err(|_| {
+                    ParseError::unexpected("a rule number, `|` or a quoted character", word)
+                        .at_column(column)
+                })?;
+                rules.last_mut().unwrap().push(index);
+            }
+        }
+
+        Ok(Rule::Composite(rules))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!("\"a\"".parse::<Rule>().unwrap(), Rule::Final('a'));
+        assert_eq!(
+            "1 21 | 3 41".parse::<Rule>().unwrap(),
+            Rule::Composite(vec![vec![1, 21], vec![3, 41]])
+        )
+    }
+
+    #[test]
+    fn test_validation() {
+        let input = Input::from("0: 1 2\n1: \"a\"\n2: 1 3 | 3 1\n3: \"b\"");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        let rules = RuleSet::parse(&lines).unwrap();
+
+        let regex = rules.rule(0).regex(&rules.rules);
+        assert_eq!(regex, "(?:a(?:ab|ba))");
+
+        assert!(rules.validate("aab"));
+        assert!(rules.validate("aba"));
+        assert!(!rules.validate("aaabb"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = Input::from("0: 1 2\n1: \"a\"\n2: \"b\"\n1: \"b\"");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        let error = RuleSet::parse(&lines).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a rule number not used before, found `1` at line 4, column 1"
+        );
+
+        let input = Input::from("0: 1 2\n1: \"a\"");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        let error = RuleSet::parse(&lines).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+
+        let input = Input::from("0: 1 a");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        let error = RuleSet::parse(&lines).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+
+        let input = Input::from("0: 1\n100000000000000: \"a\"");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        let error = RuleSet::parse(&lines).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_parse_loops() {
+        let input = Input::from("0: 1 2\n1: \"a\"\n2: 1 3 | 3 0\n3: \"b\"");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        let error = RuleSet::parse(&lines).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a rule that doesn't lead back to itself, found `2: 1 3 | 3 0` at line 3, column 1"
+        );
+
+        let input = Input::from("0: 0");
+        let lines: Vec<Line> = input.numbered_lines().collect();
+        assert!(RuleSet::parse(&lines).is_err());
+    }
+
+    fn parse_sample(sample: &str) -> Day19 {
+        Day19::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(2));
+        // No rules 8 and 11 to replace
+        assert_eq!(day.part2(), Answer::None);
+    }
+
+    #[test]
+    fn test_sample_with_loops() {
+        let day = parse_sample(include_str!("../sample2.txt"));
+        assert_eq!(day.part1(), Answer::from(3));
+        assert_eq!(day.part2(), Answer::from(12));
+    }
+}
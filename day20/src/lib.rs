@@ -0,0 +1,468 @@
+use std::collections::HashSet;
+
+use aoc::{parse::number, trace, Answer, Grid, Input, Line, ParseError, Solution};
+
+pub struct Day20 {
+    tiles: Vec<Tile>,
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    fn parse(input: &Input) -> Result<Day20, ParseError> {
+        let tiles: Vec<Tile> = input
+            .numbered_groups()
+            .map(Tile::parse)
+            .collect::<Result<_, _>>()?;
+        if tiles.is_empty() {
+            return Err(input.end().missing("a tile"));
+        }
+
+        // Tiles are rotated and lined up with each other, so they have to be
+        // squares of the same size
+        let size = tiles.first().map_or(0, |tile| tile.pixels.height()).max(1);
+        for (group, tile) in input.numbered_groups().zip(&tiles) {
+            let (width, height) = (tile.pixels.width(), tile.pixels.height());
+
+            if height != size || width != size {
+                return Err(ParseError::unexpected(
+                    format!("a {0}x{0} tile", size),
+                    format!("a {}x{} tile", width, height),
+                )
+                .on_line(group[0].number));
+            }
+        }
+
+        Ok(Day20 { tiles })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut corner_ids = vec![];
+
+        for tile in &self.tiles {
+            if tile.is_corner(&self.tiles) {
+                trace!("corner_found", tile = tile.id);
+                corner_ids.push(tile.id);
+            }
+        }
+
+        corner_ids.iter().product::<usize>().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let supertile = build_supertile(self.tiles.clone());
+
+        let seamonster = Input::from(
+            "Tile 2:\n\
+             ..................#.\n\
+             #....##....##....###\n\
+             .#..#..#..#..#..#...",
+        );
+        let seamonster_tile = Tile::parse(seamonster.numbered_lines()).unwrap();
+
+        // Sea monsters can overlap, pixels they share only count once
+        let seamonster_pixels = supertile.overlapping_pixels(&seamonster_tile);
+        trace!(
+            "sea_monsters_found",
+            pixels = seamonster_pixels.len(),
+            occupied = supertile.count_occupied()
+        );
+        (supertile.count_occupied() - seamonster_pixels.len()).into()
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct Tile {
+    id: usize,
+    pixels: Grid<bool>,
+}
+
+impl Tile {
+    fn parse<'a>(lines: impl IntoIterator<Item = Line<'a>>) -> Result<Tile, ParseError> {
+        let mut lines = lines.into_iter();
+        let header = lines
+            .next()
+            .ok_or_else(|| ParseError::missing("`Tile <id>:`"))?;
+        let id = header.parse(|text| match text.strip_prefix("Tile ") {
+            Some(rest) if rest.ends_with(':') => number(&rest[..rest.len() - 1], 6),
+            _ => Err(ParseError::unexpected("`Tile <id>:`", text)),
+        })?;
+
+        let rows: Vec<Line> = lines.take_while(|line| !line.is_empty()).collect();
+        let pixels = Grid::parse(rows.iter().copied(), "`.` or `#`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Tile { id, pixels })
+    }
+
+    fn build_supertile(tiles: Vec<Vec<Tile>>) -> Tile {
+        let mut pixels: Vec<Vec<bool>> = vec![];
+
+        for supertile_row in tiles {
+            for y in 0..supertile_row.first().unwrap().pixels.height() {
+                pixels.push(
+                    supertile_row
+                        .iter()
+                        .flat_map(|tile| tile.pixels.row(y).to_vec())
+                        .collect(),
+                );
+            }
+        }
+
+        Tile {
+            id: 1,
+            pixels: Grid::from_rows(pixels),
+        }
+    }
+
+    fn is_corner(&self, tiles: &[Tile]) -> bool {
+        // This will check for tiles that only have two matching tiles. It's possible
+        // that not all corners are found this way - maybe a tile corner has 3 matching
+        // tiles but there's no combination that works with all others while also
+        // keeping them together.
+        // It did work for part 1 though.
+        let count = tiles
+            .iter()
+            .filter(|other| other.id != self.id)
+            .filter(|other| self.matches(other))
+            .count();
+
+        count == 2
+    }
+
+    fn edges(&self) -> HashSet<Vec<bool>> {
+        let mut set = HashSet::new();
+
+        for edge in [
+            self.top_row(),
+            self.bottom_row(),
+            self.left_column(),
+            self.right_column(),
+        ] {
+            let mut inverted = edge.clone();
+            inverted.reverse();
+            set.insert(edge);
+            set.insert(inverted);
+        }
+
+        set
+    }
+
+    fn matches(&self, other: &Tile) -> bool {
+        self.edges().intersection(&other.edges()).next().is_some()
+    }
+
+    fn matches_edge(&self, edge: &Vec<bool>) -> bool {
+        self.edges().contains(edge)
+    }
+
+    fn transform_to_top_right(&mut self, tiles: &[Tile]) {
+        // self is assumed to be a corner tile
+        let neighbors: Vec<Tile> = tiles
+            .iter()
+            .filter(|tile| self.matches(tile))
+            .take(2)
+            .cloned()
+            .collect();
+
+        for _ in 0..4 {
+            if self.matches_as_top_right_corner(&neighbors) {
+                return;
+            }
+
+            self.flip_horizontal();
+
+            if self.matches_as_top_right_corner(&neighbors) {
+                return;
+            }
+
+            self.flip_vertical();
+
+            if self.matches_as_top_right_corner(&neighbors) {
+                return;
+            }
+
+            self.flip_horizontal();
+
+            if self.matches_as_top_right_corner(&neighbors) {
+                return;
+            }
+
+            self.flip_vertical();
+            self.rotate();
+        }
+    }
+
+    fn matches_as_top_right_corner(&self, neighbors: &[Tile]) -> bool {
+        neighbors
+            .iter()
+            .any(|tile| tile.matches_edge(&self.right_column()))
+            && neighbors
+                .iter()
+                .any(|tile| tile.matches_edge(&self.bottom_row()))
+    }
+
+    fn rotate(&mut self) {
+        self.pixels = self.pixels.rotated();
+    }
+
+    fn flip_horizontal(&mut self) {
+        self.pixels = self.pixels.flipped_horizontally();
+    }
+
+    fn flip_vertical(&mut self) {
+        self.pixels = self.pixels.flipped_vertically();
+    }
+
+    fn top_row(&self) -> Vec<bool> {
+        self.pixels.row(0).to_vec()
+    }
+
+    fn bottom_row(&self) -> Vec<bool> {
+        self.pixels.row(self.pixels.height() - 1).to_vec()
+    }
+
+    fn left_column(&self) -> Vec<bool> {
+        self.pixels.column(0).copied().collect()
+    }
+
+    fn right_column(&self) -> Vec<bool> {
+        self.pixels
+            .column(self.pixels.width() - 1)
+            .copied()
+            .collect()
+    }
+
+    fn remove_borders(&mut self) {
+        let (width, height) = (self.pixels.width(), self.pixels.height());
+        self.pixels = self.pixels.subgrid(1, 1, width - 2, height - 2);
+    }
+
+    fn is_left_of(&mut self, other: &Tile) -> bool {
+        for _ in 0..4 {
+            if self.left_column() == other.right_column() {
+                return true;
+            }
+
+            self.flip_horizontal();
+
+            if self.left_column() == other.right_column() {
+                return true;
+            }
+
+            self.flip_vertical();
+
+            if self.left_column() == other.right_column() {
+                return true;
+            }
+
+            self.flip_horizontal();
+
+            if self.left_column() == other.right_column() {
+                return true;
+            }
+
+            self.flip_vertical();
+            self.rotate();
+        }
+
+        false
+    }
+
+    fn is_under(&mut self, other: &Tile) -> bool {
+        for _ in 0..4 {
+            if self.top_row() == other.bottom_row() {
+                return true;
+            }
+
+            self.flip_horizontal();
+
+            if self.top_row() == other.bottom_row() {
+                return true;
+            }
+
+            self.flip_vertical();
+
+            if self.top_row() == other.bottom_row() {
+                return true;
+            }
+
+            self.flip_horizontal();
+
+            if self.top_row() == other.bottom_row() {
+                return true;
+            }
+
+            self.flip_vertical();
+            self.rotate();
+        }
+
+        false
+    }
+
+    /// The pixels `tile` covers wherever it shows up, in whichever orientation
+    /// of this tile it covers the most. Random pixels sometimes line up into a
+    /// match in another orientation too, those don't count.
+    fn overlapping_pixels(&self, tile: &Tile) -> HashSet<(usize, usize)> {
+        self.pixels
+            .orientations()
+            .into_iter()
+            .map(|pixels| overlapping_pixels(&pixels, &tile.pixels))
+            .max_by_key(HashSet::len)
+            .unwrap()
+    }
+
+    fn count_occupied(&self) -> usize {
+        self.pixels.cells().filter(|pixel| **pixel).count()
+    }
+}
+
+/// The pixels of `pixels` covered by `pattern` wherever all of the pattern's
+/// pixels are set in them.
+fn overlapping_pixels(pixels: &Grid<bool>, pattern: &Grid<bool>) -> HashSet<(usize, usize)> {
+    let shape: Vec<(usize, usize)> = pattern
+        .iter()
+        .filter(|(_, pixel)| **pixel)
+        .map(|(position, _)| position)
+        .collect();
+    let mut covered = HashSet::new();
+
+    for y in 0..=pixels.height() - pattern.height() {
+        for x in 0..=pixels.width() - pattern.width() {
+            if shape.iter().all(|(dx, dy)| pixels[(x + dx, y + dy)]) {
+                covered.extend(shape.iter().map(|(dx, dy)| (x + dx, y + dy)));
+            }
+        }
+    }
+
+    covered
+}
+
+fn build_supertile(mut tiles: Vec<Tile>) -> Tile {
+    // The assumption made here is that each tile only matches the one it will actually go next to.
+    // If a tile (whether flipped, rotated or not) matches another tile, then those two tiles go
+    // together. This assumption was at least confirmed for the corners in part 1 (they have exactly
+    // two possible neighbors, and there are only 4 tiles where that's true).
+
+    // Find one random corner to start with
+    let mut corner = tiles.remove(
+        tiles
+            .iter()
+            .position(|tile| tile.is_corner(&tiles))
+            .unwrap(),
+    );
+
+    // Rotate the corner so that it is oriented properly (the two matching tiles are on the right
+    // and on the bottom)
+    corner.transform_to_top_right(&tiles);
+    trace!("tile_placed", tile = corner.id, row = 0, column = 0);
+
+    let mut last_tile = corner.clone();
+    let mut supertile: Vec<Vec<Tile>> = vec![vec![corner]];
+
+    while !tiles.is_empty() {
+        while let Some(next_tile_position) = tiles
+            .iter_mut()
+            .position(|tile| tile.is_left_of(&last_tile))
+        {
+            let next_tile = tiles.remove(next_tile_position);
+            trace!(
+                "tile_placed",
+                tile = next_tile.id,
+                row = supertile.len() - 1,
+                column = supertile.last().unwrap().len()
+            );
+            last_tile = next_tile.clone();
+            supertile.last_mut().unwrap().push(next_tile);
+        }
+
+        if !tiles.is_empty() {
+            let next_tile_position = tiles
+                .iter_mut()
+                .position(|tile| tile.is_under(supertile.last().unwrap().first().unwrap()))
+                .unwrap();
+
+            let next_tile = tiles.remove(next_tile_position);
+            trace!(
+                "tile_placed",
+                tile = next_tile.id,
+                row = supertile.len(),
+                column = 0
+            );
+            last_tile = next_tile.clone();
+            supertile.push(vec![next_tile]);
+        }
+    }
+
+    supertile
+        .iter_mut()
+        .for_each(|row| row.iter_mut().for_each(|tile| tile.remove_borders()));
+
+    Tile::build_supertile(supertile)
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    fn parse_tile(lines: &[&str]) -> Tile {
+        let input = Input::from(lines.join("\n"));
+        Tile::parse(input.numbered_lines()).unwrap()
+    }
+
+    #[test]
+    fn test_rotate_tile() {
+        let mut tile = parse_tile(&["Tile 1234:", "..#", "#..", "###"]);
+        tile.rotate();
+
+        let rotated_tile = parse_tile(&["Tile 1234:", "##.", "#..", "#.#"]);
+        assert_eq!(tile, rotated_tile);
+    }
+
+    #[test]
+    fn test_flip_horizontal() {
+        let mut tile = parse_tile(&["Tile 1234:", "..#", "#..", "###"]);
+        tile.flip_horizontal();
+
+        let rotated_tile = parse_tile(&["Tile 1234:", "#..", "..#", "###"]);
+        assert_eq!(tile, rotated_tile);
+    }
+
+    #[test]
+    fn test_flip_vertical() {
+        let mut tile = parse_tile(&["Tile 1234:", "..#", "#..", "###"]);
+        tile.flip_vertical();
+
+        let rotated_tile = parse_tile(&["Tile 1234:", "###", "#..", "..#"]);
+        assert_eq!(tile, rotated_tile);
+    }
+
+    #[test]
+    fn test_transform_to_top_right() {
+        let mut top_right_tile = parse_tile(&["Tile 1234:", "..#", "#..", "###"]);
+        let neighbors = vec![
+            parse_tile(&["Tile 2345:", "#..", ".#.", "..."]),
+            parse_tile(&["Tile 2345:", ".##", "...", "..#"]),
+        ];
+
+        top_right_tile.transform_to_top_right(&neighbors);
+
+        let rotated_tile = parse_tile(&["Tile 1234:", "###", "..#", "#.."]);
+        assert_eq!(top_right_tile, rotated_tile);
+    }
+
+    fn parse_sample(sample: &str) -> Day20 {
+        Day20::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(20899048083289_u64));
+        assert_eq!(day.part2(), Answer::from(273));
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{parse::column, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day21 {
+    foods: Vec<(Vec<String>, Vec<String>)>,
+}
+
+impl Day21 {
+    // Returns every ingredient, along with the ingredients that could contain each allergen
+    fn ingredients_by_allergens(&self) -> (HashSet<String>, HashMap<String, HashSet<String>>) {
+        let mut all_ingredients: HashSet<String> = HashSet::new();
+        let mut ingredients_by_allergens: HashMap<String, HashSet<String>> = HashMap::new();
+
+        for food in &self.foods {
+            let (new_ingredients, allergens) = food;
+
+            all_ingredients = all_ingredients
+                .union(&new_ingredients.iter().cloned().collect())
+                .cloned()
+                .collect();
+
+            for allergen in allergens {
+                ingredients_by_allergens
+                    .entry(allergen.clone())
+                    .and_modify(|ingredients| {
+                        *ingredients = ingredients
+                            .intersection(&new_ingredients.iter().cloned().collect())
+                            .cloned()
+                            .collect()
+                    })
+                    .or_insert(new_ingredients.iter().cloned().collect::<HashSet<String>>());
+            }
+        }
+
+        (all_ingredients, ingredients_by_allergens)
+    }
+
+    fn ingredients_without_allergens(
+        all_ingredients: &HashSet<String>,
+        ingredients_by_allergens: &HashMap<String, HashSet<String>>,
+    ) -> HashSet<String> {
+        let ingredients_with_potential_allergens = ingredients_by_allergens
+            .values()
+            .fold(HashSet::new(), |acc, ingredients| {
+                acc.union(ingredients).cloned().collect()
+            });
+
+        all_ingredients
+            .difference(&ingredients_with_potential_allergens)
+            .cloned()
+            .collect()
+    }
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    fn parse(input: &Input) -> Result<Day21, ParseError> {
+        let foods = input
+            .numbered_lines()
+            .map(|line| line.parse(parse_food))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day21 { foods })
+    }
+
+    fn part1(&self) -> Answer {
+        let (all_ingredients, ingredients_by_allergens) = self.ingredients_by_allergens();
+        let ingredients_without_allergens =
+            Day21::ingredients_without_allergens(&all_ingredients, &ingredients_by_allergens);
+        trace!(
+            "safe_ingredients_found",
+            safe = ingredients_without_allergens.len(),
+            ingredients = all_ingredients.len()
+        );
+
+        self.foods
+            .iter()
+            .map(|(ingredients, _)| {
+                ingredients_without_allergens
+                    .intersection(&ingredients.iter().cloned().collect())
+                    .count()
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (all_ingredients, ingredients_by_allergens) = self.ingredients_by_allergens();
+        let ingredients_without_allergens =
+            Day21::ingredients_without_allergens(&all_ingredients, &ingredients_by_allergens);
+
+        let mut unavailable_ingredients = ingredients_without_allergens;
+        let mut pending_allergens: Vec<(String, HashSet<String>)> =
+            ingredients_by_allergens.into_iter().collect();
+        let mut allergen_by_ingredient: Vec<(String, String)> = vec![];
+
+        // Some allergen always has a single candidate left, which rules that
+        // ingredient out for all the others
+        while !pending_allergens.is_empty() {
+            let resolved = pending_allergens
+                .iter()
+                .position(|(_, ingredients)| {
+                    ingredients.difference(&unavailable_ingredients).count() == 1
+                })
+                .expect("Allergens can't be matched to a single ingredient");
+            let (allergen, ingredients) = pending_allergens.swap_remove(resolved);
+
+            let allergen_ingredient = ingredients
+                .difference(&unavailable_ingredients)
+                .next()
+                .unwrap()
+                .clone();
+
+            trace!(
+                "allergen_assigned",
+                allergen = allergen,
+                ingredient = allergen_ingredient,
+                candidates = ingredients.len()
+            );
+            unavailable_ingredients.insert(allergen_ingredient.clone());
+
+            allergen_by_ingredient.push((allergen, allergen_ingredient));
+        }
+
+        allergen_by_ingredient.sort_by_key(|(allergen, _)| allergen.clone());
+
+        allergen_by_ingredient
+            .iter()
+            .map(|(_, ingredient)| ingredient.clone())
+            .collect::<Vec<String>>()
+            .join(",")
+            .into()
+    }
+}
+
+fn parse_food(food: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    // Look, no regex
+    let allergen_start = food.find('(').ok_or_else(|| {
+        ParseError::missing("`(contains ...)`").at_column(column(food, food.len()))
+    })?;
+    let (ingredients, allergens) = food.split_at(allergen_start);
+    let allergens = allergens
+        .strip_prefix("(contains ")
+        .ok_or_else(|| {
+            ParseError::unexpected("`(contains ...)`", allergens)
+                .at_column(column(food, allergen_start))
+        })?
+        .strip_suffix(")")
+        .ok_or_else(|| ParseError::missing("`)`").at_column(column(food, food.len())))?;
+
+    Ok((
+        ingredients
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect(),
+        allergens
+            .trim()
+            .split(", ")
+            .map(|s| s.to_string())
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_food() {
+        assert_eq!(
+            parse_food("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)"),
+            Ok((
+                vec![
+                    "mxmxvkd".to_string(),
+                    "kfcds".to_string(),
+                    "sqjhc".to_string(),
+                    "nhms".to_string()
+                ],
+                vec!["dairy".to_string(), "fish".to_string()]
+            ))
+        )
+    }
+
+    #[test]
+    fn test_parse_food_errors() {
+        let error = parse_food("mxmxvkd kfcds (has dairy)").unwrap_err();
+        assert_eq!(error.column, 15);
+
+        let error = parse_food("mxmxvkd kfcds (contains dairy").unwrap_err();
+        assert_eq!(error.to_string(), "missing `)` at line 1, column 30");
+    }
+
+    fn parse_sample(sample: &str) -> Day21 {
+        Day21::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(5));
+        assert_eq!(day.part2(), Answer::from("mxmxvkd,sqjhc,fvjkl"));
+    }
+}
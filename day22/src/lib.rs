@@ -0,0 +1,171 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use aoc::{parse::number, trace, Answer, Input, ParseError, Solution};
+
+pub struct Day22 {
+    player_1_deck: VecDeque<usize>,
+    player_2_deck: VecDeque<usize>,
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    fn parse(input: &Input) -> Result<Day22, ParseError> {
+        let mut decks = input.numbered_groups().enumerate().map(|(i, group)| {
+            let header = format!("Player {}:", i + 1);
+            if group[0].text != header {
+                return Err(
+                    ParseError::unexpected(format!("`{}`", header), group[0].text)
+                        .on_line(group[0].number),
+                );
+            }
+
+            group
+                .iter()
+                .skip(1)
+                .map(|line| line.parse(|text| number(text, 1)))
+                .collect::<Result<VecDeque<usize>, _>>()
+        });
+
+        let player_1_deck = decks
+            .next()
+            .ok_or_else(|| input.end().missing("`Player 1:`"))??;
+        let player_2_deck = decks
+            .next()
+            .ok_or_else(|| input.end().missing("`Player 2:`"))??;
+
+        Ok(Day22 {
+            player_1_deck,
+            player_2_deck,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut player_1 = self.player_1_deck.clone();
+        let mut player_2 = self.player_2_deck.clone();
+        let mut rounds = 0;
+
+        while !player_1.is_empty() && !player_2.is_empty() {
+            rounds += 1;
+            let player_1_card = player_1.pop_front().unwrap();
+            let player_2_card = player_2.pop_front().unwrap();
+
+            if player_1_card > player_2_card {
+                player_1.push_back(player_1_card);
+                player_1.push_back(player_2_card);
+            } else {
+                player_2.push_back(player_2_card);
+                player_2.push_back(player_1_card);
+            }
+        }
+
+        let (winner, deck) = if !player_1.is_empty() {
+            (1, player_1)
+        } else {
+            (2, player_2)
+        };
+        trace!("game_finished", winner = winner, rounds = rounds);
+
+        deck_score(&deck).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let recursive_result =
+            recursive_combat(self.player_1_deck.clone(), self.player_2_deck.clone());
+        let (winner, winner_deck) = match recursive_result {
+            RoundResult::Player1Win(deck) => (1, deck),
+            RoundResult::Player2Win(deck) => (2, deck),
+        };
+        trace!("game_finished", winner = winner);
+
+        deck_score(&winner_deck).into()
+    }
+}
+
+enum RoundResult {
+    Player1Win(VecDeque<usize>),
+    Player2Win(VecDeque<usize>),
+}
+
+fn recursive_combat(
+    mut player_1_deck: VecDeque<usize>,
+    mut player_2_deck: VecDeque<usize>,
+) -> RoundResult {
+    let mut previous_rounds = HashSet::new();
+
+    while !player_1_deck.is_empty() && !player_2_deck.is_empty() {
+        let round_hash = calculate_hash(&(&player_1_deck, &player_2_deck));
+
+        if !previous_rounds.insert(round_hash) {
+            trace!("round_repeated", rounds = previous_rounds.len());
+            return RoundResult::Player1Win(player_1_deck);
+        }
+
+        let player_1_card = player_1_deck.pop_front().unwrap();
+        let player_2_card = player_2_deck.pop_front().unwrap();
+
+        let winner = if player_1_card <= player_1_deck.len() && player_2_card <= player_2_deck.len()
+        {
+            recursive_combat(
+                player_1_deck.iter().take(player_1_card).copied().collect(),
+                player_2_deck.iter().take(player_2_card).copied().collect(),
+            )
+        } else {
+            // The actual winning dec doesn't really matter here
+            if player_1_card > player_2_card {
+                RoundResult::Player1Win(VecDeque::new())
+            } else {
+                RoundResult::Player2Win(VecDeque::new())
+            }
+        };
+
+        match winner {
+            RoundResult::Player1Win(_) => {
+                player_1_deck.push_back(player_1_card);
+                player_1_deck.push_back(player_2_card);
+            }
+            RoundResult::Player2Win(_) => {
+                player_2_deck.push_back(player_2_card);
+                player_2_deck.push_back(player_1_card);
+            }
+        }
+    }
+
+    if !player_1_deck.is_empty() {
+        RoundResult::Player1Win(player_1_deck)
+    } else {
+        RoundResult::Player2Win(player_2_deck)
+    }
+}
+
+fn deck_score(deck: &VecDeque<usize>) -> usize {
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, card)| *card * (i + 1))
+        .sum()
+}
+
+fn calculate_hash<T: Hash>(t: &T) -> u64 {
+    let mut s = DefaultHasher::new();
+    t.hash(&mut s);
+    s.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day22 {
+        Day22::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(306));
+        assert_eq!(day.part2(), Answer::from(291));
+    }
+}
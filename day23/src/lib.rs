@@ -0,0 +1,254 @@
+use std::io;
+
+use aoc::{
+    trace, Answer, Canvas, Color, Frame, Grid, Input, ParseError, Part, Solution, Visualize,
+};
+
+pub struct Day23 {
+    cups: Vec<usize>,
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    fn parse(input: &Input) -> Result<Day23, ParseError> {
+        let line = input
+            .numbered_lines()
+            .find(|line| !line.trim().is_empty())
+            .ok_or_else(|| input.end().missing("the cup labels"))?;
+
+        let cups = line.parse(|text| {
+            let labels = text.trim_end();
+            let count = labels.chars().count();
+            let mut cups: Vec<usize> = vec![];
+
+            // Labels are used as indexes when solving, so they have to be 1..=count
+            for (i, c) in labels.chars().enumerate() {
+                match c.to_digit(10).map(|label| label as usize) {
+                    Some(label) if (1..=count).contains(&label) && !cups.contains(&label) => {
+                        cups.push(label)
+                    }
+                    _ => {
+                        return Err(ParseError::unexpected(
+                            format!("each cup label from 1 to {} once", count),
+                            c.to_string(),
+                        )
+                        .at_column(i + 1))
+                    }
+                }
+            }
+
+            Ok(cups)
+        })?;
+        // A move picks up 3 cups, and still needs a destination cup other
+        // than the current one
+        if cups.len() < 5 {
+            return Err(line.missing("at least 5 cup labels"));
+        }
+
+        Ok(Day23 { cups })
+    }
+
+    fn part1(&self) -> Answer {
+        // A million cups in part 2 are too many to trace every move of
+        let cups = play(self.cups.clone(), MOVES, |cups_next, current| {
+            trace!(
+                "cups_arranged",
+                current = current,
+                cups = labels(&circle(cups_next, self.cups[0]))
+            )
+        });
+
+        labels_after_cup_1(&cups).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut cups = self.cups.clone();
+
+        for n in *cups.iter().max().unwrap() + 1..=1_000_000 {
+            cups.push(n);
+        }
+
+        let part_2_solution = solve(cups, 10_000_000);
+        let stars: Vec<usize> = part_2_solution
+            .iter()
+            .cycle()
+            .skip_while(|n| **n != 1)
+            .skip(1)
+            .take(2)
+            .copied()
+            .collect();
+        trace!("stars_found", first = stars[0], second = stars[1]);
+
+        stars.iter().product::<usize>().into()
+    }
+}
+
+impl Visualize for Day23 {
+    /// Part 2 has a million cups, far too many to draw.
+    const PARTS: &'static [Part] = &[Part::One];
+
+    /// The cups before and after each move.
+    fn visualize(&self, part: Part, canvas: &mut dyn Canvas) -> io::Result<()> {
+        if part == Part::Two {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "a million cups are too many to draw",
+            ));
+        }
+
+        let mut drawn = Ok(());
+        play(self.cups.clone(), MOVES, |cups_next, current| {
+            if drawn.is_ok() {
+                drawn = canvas.draw(&frame(&circle(cups_next, self.cups[0]), current));
+            }
+        });
+
+        drawn
+    }
+}
+
+/// How many moves the crab makes in part 1.
+const MOVES: usize = 100;
+
+/// The cups as bars as tall as their labels, in order around the circle. The
+/// current cup is red.
+fn frame(cups: &[usize], current: usize) -> Frame {
+    let height = cups.len();
+    let mut frame = Grid::new(2 * cups.len() - 1, height, Color::BLACK);
+
+    for (i, cup) in cups.iter().enumerate() {
+        let color = if *cup == current {
+            Color::RED
+        } else {
+            Color::WHITE
+        };
+
+        for y in height - cup..height {
+            frame[(2 * i, y)] = color;
+        }
+    }
+
+    frame
+}
+
+fn labels_after_cup_1(cups: &[usize]) -> String {
+    let after: Vec<usize> = cups
+        .iter()
+        .cycle()
+        .skip_while(|n| **n != 1)
+        .skip(1)
+        .take_while(|n| **n != 1)
+        .copied()
+        .collect();
+    labels(&after)
+}
+
+fn labels(cups: &[usize]) -> String {
+    cups.iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+fn solve(cups: Vec<usize>, loops: usize) -> Vec<usize> {
+    play(cups, loops, |_, _| {})
+}
+
+/// Same as [`solve`], calling `on_move` before each move and after the last
+/// one. It gets the cup after each cup, see [`circle`], and the current cup.
+fn play(cups: Vec<usize>, loops: usize, mut on_move: impl FnMut(&[usize], usize)) -> Vec<usize> {
+    let mut cups_next: Vec<Option<usize>> = vec![None; cups.len()];
+
+    for (i, cup) in cups.iter().enumerate() {
+        if i < cups.len() - 1 {
+            cups_next[*cup - 1] = Some(cups[i + 1])
+        }
+    }
+    cups_next[*cups.last().unwrap() - 1] = Some(cups[0]);
+
+    let mut cups_next: Vec<usize> = cups_next
+        .into_iter()
+        .collect::<Option<Vec<usize>>>()
+        .unwrap();
+
+    let mut current = cups[0];
+
+    let max = *cups.iter().max().unwrap();
+    let min = *cups.iter().min().unwrap();
+
+    for _ in 0..loops {
+        on_move(&cups_next, current);
+
+        let first_removed = cups_next[current - 1];
+        let second_removed = cups_next[first_removed - 1];
+        let removed = [first_removed, second_removed, cups_next[second_removed - 1]];
+
+        cups_next[current - 1] = cups_next[*removed.last().unwrap() - 1];
+
+        let mut destination = current;
+
+        loop {
+            destination = if destination > min {
+                destination - 1
+            } else {
+                max
+            };
+
+            if !removed.contains(&destination) {
+                break;
+            }
+        }
+
+        cups_next[*removed.last().unwrap() - 1] = cups_next[destination - 1];
+        cups_next[destination - 1] = *removed.first().unwrap();
+        current = cups_next[current - 1];
+    }
+
+    on_move(&cups_next, current);
+
+    circle(&cups_next, cups[0])
+}
+
+/// The cups in order around the circle starting from `first`, given the label
+/// of the cup after each cup, by label from 1.
+fn circle(cups_next: &[usize], first: usize) -> Vec<usize> {
+    let mut result: Vec<usize> = vec![first];
+
+    for i in 1..cups_next.len() {
+        result.push(cups_next[result[i - 1] - 1]);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day23 {
+        Day23::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from("67384529"));
+        assert_eq!(day.part2(), Answer::from(149245887792_u64));
+    }
+
+    #[test]
+    fn test_ten_moves() {
+        let cups = solve(parse_sample(include_str!("../sample.txt")).cups, 10);
+        assert_eq!(labels_after_cup_1(&cups), "92658374");
+    }
+
+    #[test]
+    fn test_too_few_cups() {
+        let error = Day23::parse(&Input::from("2143\n")).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "missing at least 5 cup labels at line 1, column 5"
+        );
+    }
+}
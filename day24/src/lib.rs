@@ -0,0 +1,212 @@
+use std::io;
+
+use aoc::{
+    automaton::Lattice, trace, Answer, Automaton, Canvas, Color, Frame, Input, ParseError, Part,
+    Point, Rule, Solution, SparseGrid, Visualize,
+};
+
+pub struct Day24 {
+    paths: Vec<Vec<Direction>>,
+}
+
+impl Day24 {
+    fn flipped_tiles(&self) -> SparseGrid<Hex> {
+        let mut flipped_tiles = SparseGrid::new();
+
+        self.paths.iter().for_each(|directions| {
+            let Hex { q, r } = target(directions);
+            let black = flipped_tiles.toggle(Hex { q, r });
+            trace!("tile_flipped", q = q, r = r, black = black);
+        });
+
+        flipped_tiles
+    }
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    fn parse(input: &Input) -> Result<Day24, ParseError> {
+        let paths = input
+            .numbered_lines()
+            .map(|line| line.parse(parse_directions))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day24 { paths })
+    }
+
+    fn part1(&self) -> Answer {
+        self.flipped_tiles().len().into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut floor = exhibit(self.flipped_tiles());
+
+        for day in 1..=DAYS {
+            floor.step();
+            trace!("day_passed", day = day, black = floor.live().len());
+        }
+
+        floor.live().len().into()
+    }
+}
+
+impl Visualize for Day24 {
+    /// Part 1 flips tiles one path at a time, part 2 draws the floor every day.
+    fn visualize(&self, part: Part, canvas: &mut dyn Canvas) -> io::Result<()> {
+        match part {
+            Part::One => {
+                let mut flipped_tiles = SparseGrid::new();
+
+                for directions in &self.paths {
+                    flipped_tiles.toggle(target(directions));
+                    canvas.draw(&frame(&flipped_tiles))?;
+                }
+            }
+            Part::Two => {
+                let mut floor = exhibit(self.flipped_tiles());
+
+                for day in 0..=DAYS {
+                    if day > 0 {
+                        floor.step();
+                    }
+                    canvas.draw(&frame(floor.live()))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The tile at the end of a path from the reference tile.
+fn target(directions: &[Direction]) -> Hex {
+    directions
+        .iter()
+        .fold(Hex { q: 0, r: 0 }, |hex, direction| hex.step(direction))
+}
+
+/// How many days the exhibit lasts.
+const DAYS: usize = 100;
+
+/// The tiles that flip every day, as an automaton of black tiles. Black tiles
+/// with none or more than two black neighbours turn white, white ones with
+/// exactly two turn black.
+fn exhibit(flipped_tiles: SparseGrid<Hex>) -> Automaton<Hex, Lattice> {
+    let rule = Rule {
+        birth: 2..=2,
+        survival: 1..=2,
+    };
+    Automaton::new(Lattice, rule, flipped_tiles)
+}
+
+/// The floor with black tiles in black. Rows of tiles are shifted half a tile
+/// from each other, each tile two pixels wide so the halves line up.
+fn frame(flipped_tiles: &SparseGrid<Hex>) -> Frame {
+    let pixels: SparseGrid<[isize; 2]> = flipped_tiles
+        .points()
+        .flat_map(|Hex { q, r }| [[2 * q + r, r], [2 * q + r + 1, r]])
+        .collect();
+
+    pixels.to_grid(|pixel| match pixel {
+        Some(()) => Color::BLACK,
+        None => Color::WHITE,
+    })
+}
+
+/// A hexagonal tile in axial coordinates: `q` grows to the east, `r` to the
+/// south east.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Hex {
+    q: isize,
+    r: isize,
+}
+
+impl Hex {
+    fn step(self, direction: &Direction) -> Hex {
+        let (dq, dr) = match direction {
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+            Direction::SouthEast => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::NorthEast => (1, -1),
+            Direction::NorthWest => (0, -1),
+        };
+
+        Hex {
+            q: self.q + dq,
+            r: self.r + dr,
+        }
+    }
+}
+
+impl Point for Hex {
+    fn neighbours(self) -> Vec<Hex> {
+        Direction::ALL
+            .iter()
+            .map(|direction| self.step(direction))
+            .collect()
+    }
+}
+
+enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    const ALL: [Direction; 6] = [
+        Direction::East,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+        Direction::NorthEast,
+    ];
+}
+
+fn parse_directions(line: &str) -> Result<Vec<Direction>, ParseError> {
+    let mut chars = line.chars().enumerate();
+    let mut directions = vec![];
+    let unexpected = |i: usize, found: &str| {
+        ParseError::unexpected("one of `e`, `se`, `sw`, `w`, `nw` or `ne`", found).at_column(i + 1)
+    };
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            'e' => directions.push(Direction::East),
+            'w' => directions.push(Direction::West),
+            's' | 'n' => match (c, chars.next()) {
+                ('s', Some((_, 'e'))) => directions.push(Direction::SouthEast),
+                ('s', Some((_, 'w'))) => directions.push(Direction::SouthWest),
+                ('n', Some((_, 'e'))) => directions.push(Direction::NorthEast),
+                ('n', Some((_, 'w'))) => directions.push(Direction::NorthWest),
+                (_, Some((_, other))) => return Err(unexpected(i, &format!("{}{}", c, other))),
+                (_, None) => return Err(unexpected(i, &c.to_string())),
+            },
+            other => return Err(unexpected(i, &other.to_string())),
+        }
+    }
+
+    Ok(directions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day24 {
+        Day24::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(10));
+        assert_eq!(day.part2(), Answer::from(2208));
+    }
+}
@@ -0,0 +1,101 @@
+use aoc::{
+    parse::{number, words},
+    trace, Answer, Input, ParseError, Solution,
+};
+
+static PRIVATE_KEY_SUBJECT_NUMBER: usize = 7;
+
+pub struct Day25 {
+    card_public_key: usize,
+    door_public_key: usize,
+}
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    fn parse(input: &Input) -> Result<Day25, ParseError> {
+        // Both keys may be on the same line when given inline
+        let mut public_keys = input.numbered_lines().flat_map(|line| {
+            words(line.text).map(move |(column, key)| {
+                line.parse(|_| match number(key, column)? {
+                    key @ 1..=20201226 => Ok(key),
+                    _ => Err(ParseError::unexpected("a public key below 20201227", key)
+                        .at_column(column)),
+                })
+            })
+        });
+
+        let card_public_key = public_keys
+            .next()
+            .ok_or_else(|| input.end().missing("the card's public key"))??;
+        let door_public_key = public_keys
+            .next()
+            .ok_or_else(|| input.end().missing("the door's public key"))??;
+
+        Ok(Day25 {
+            card_public_key,
+            door_public_key,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let card_loop_size = break_encryption(self.card_public_key);
+        trace!(
+            "loop_size_found",
+            public_key = self.card_public_key,
+            loop_size = card_loop_size
+        );
+        encrypt(self.door_public_key, card_loop_size).into()
+    }
+
+    // There is no second puzzle on the last day
+    fn part2(&self) -> Answer {
+        Answer::None
+    }
+}
+
+fn break_encryption(public_key: usize) -> usize {
+    let mut value = 1;
+    let mut loop_size = 0;
+
+    while value != public_key {
+        value *= PRIVATE_KEY_SUBJECT_NUMBER;
+        value %= 20201227;
+
+        loop_size += 1;
+    }
+
+    loop_size
+}
+
+fn encrypt(base_value: usize, loop_size: usize) -> usize {
+    let mut value = 1;
+
+    for _ in 0..loop_size {
+        value *= base_value;
+        value %= 20201227;
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_sample(sample: &str) -> Day25 {
+        Day25::parse(&Input::from(sample)).unwrap()
+    }
+
+    #[test]
+    fn test_break_encryption() {
+        assert_eq!(break_encryption(5764801), 8);
+        assert_eq!(break_encryption(17807724), 11);
+    }
+
+    #[test]
+    fn test_sample() {
+        let day = parse_sample(include_str!("../sample.txt"));
+        assert_eq!(day.part1(), Answer::from(14897079));
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use aoc::{Input, Solution};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let _ = day04::Day04::parse(&Input::from(text));
+});
@@ -0,0 +1,48 @@
+//! Expense reports with exactly one pair and one triple of entries summing to 2020.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 1;
+pub const SIZE: &str = "expense entries, at least 5";
+pub const DEFAULT_SIZE: usize = 200;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // The triple is made of entries from 400 to 1009, so no two of them add up to 2020
+    let mut triple = [0; 3];
+    while triple[0] == triple[1] || triple[1] == triple[2] || triple[0] == triple[2] {
+        let x = rng.gen_range(400..800);
+        let y = rng.gen_range(1011 - x..=1009.min(1620 - x));
+        triple = [x, y, 2020 - x - y];
+    }
+    let mut entries = triple.to_vec();
+
+    loop {
+        let a = rng.gen_range(1..1010);
+        if !adds_up_to_2020(&entries, a) && !adds_up_to_2020(&entries, 2020 - a) {
+            entries.extend(&[a, 2020 - a]);
+            break;
+        }
+    }
+
+    while entries.len() < size.max(5) {
+        let entry = rng.gen_range(1..2020);
+        if !entries.contains(&entry) && !adds_up_to_2020(&entries, entry) {
+            entries.push(entry);
+        }
+    }
+
+    entries.shuffle(rng);
+    lines(entries)
+}
+
+/// Whether `entry` would make another pair or triple adding up to 2020 with `entries`.
+fn adds_up_to_2020(entries: &[usize], entry: usize) -> bool {
+    entries.iter().enumerate().any(|(i, &other)| {
+        entry + other == 2020
+            || entries[i + 1..]
+                .iter()
+                .any(|&third| entry + other + third == 2020)
+    })
+}
@@ -0,0 +1,36 @@
+//! Password database lines like `1-3 a: abcde`. Passwords are at least as long
+//! as the policy's second number, which part 2 reads as a position.
+
+use rand::Rng as _;
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 2;
+pub const SIZE: &str = "passwords";
+pub const DEFAULT_SIZE: usize = 1000;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let low = rng.gen_range(1..=10);
+        let high = rng.gen_range(low + 1..=20);
+        let policy_letter = letter(rng);
+
+        // Favouring the policy's letter so passwords comply about half the time
+        let length = rng.gen_range(high..=high + 5);
+        let password: String = (0..length)
+            .map(|_| {
+                if rng.gen_bool(0.4) {
+                    policy_letter
+                } else {
+                    letter(rng)
+                }
+            })
+            .collect();
+
+        format!("{}-{} {}: {}", low, high, policy_letter, password)
+    }))
+}
+
+fn letter(rng: &mut Rng) -> char {
+    rng.gen_range(b'a'..=b'z') as char
+}
@@ -0,0 +1,11 @@
+//! Maps of open squares and trees, 31 squares wide like the real ones.
+
+use crate::{grid, Rng};
+
+pub const DAY: u8 = 3;
+pub const SIZE: &str = "rows";
+pub const DEFAULT_SIZE: usize = 323;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    grid(rng, 31, size, '#', '.', 0.25)
+}
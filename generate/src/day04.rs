@@ -0,0 +1,86 @@
+//! Batches of passports, each field missing or with an invalid value every now
+//! and then. Fields of a passport are split into lines at random.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Rng;
+
+pub const DAY: u8 = 4;
+pub const SIZE: &str = "passports";
+pub const DEFAULT_SIZE: usize = 250;
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let passports: Vec<String> = (0..size).map(|_| passport(rng)).collect();
+    passports.join("\n")
+}
+
+fn passport(rng: &mut Rng) -> String {
+    let mut fields = vec![];
+    for field in &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"] {
+        if rng.gen_bool(if *field == "cid" { 0.5 } else { 0.92 }) {
+            let valid = rng.gen_bool(0.9);
+            fields.push(format!("{}:{}", field, value(rng, field, valid)));
+        }
+    }
+    fields.shuffle(rng);
+
+    let mut passport = String::new();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            passport.push(if rng.gen_bool(0.3) { '\n' } else { ' ' });
+        }
+        passport.push_str(field);
+    }
+    passport.push('\n');
+    passport
+}
+
+fn value(rng: &mut Rng, field: &str, valid: bool) -> String {
+    match (field, valid) {
+        ("byr", true) => rng.gen_range(1920..=2002).to_string(),
+        ("byr", false) => rng.gen_range(1900..1920).to_string(),
+        ("iyr", true) => rng.gen_range(2010..=2020).to_string(),
+        ("iyr", false) => rng.gen_range(2021..2030).to_string(),
+        ("eyr", true) => rng.gen_range(2020..=2030).to_string(),
+        ("eyr", false) => rng.gen_range(2000..2020).to_string(),
+        ("hgt", true) if rng.gen() => format!("{}cm", rng.gen_range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.gen_range(59..=76)),
+        ("hgt", false) => match rng.gen_range(0..3) {
+            0 => format!("{}cm", rng.gen_range(100..150)),
+            1 => format!("{}in", rng.gen_range(77..90)),
+            _ => rng.gen_range(59..193).to_string(),
+        },
+        ("hcl", valid) => {
+            let digits: String = (0..6)
+                .map(|_| {
+                    if valid {
+                        *b"0123456789abcdef".choose(rng).unwrap() as char
+                    } else {
+                        *b"0123456789xyz".choose(rng).unwrap() as char
+                    }
+                })
+                .collect();
+
+            if valid || rng.gen() {
+                format!("#{}", digits)
+            } else {
+                digits
+            }
+        }
+        ("ecl", true) => EYE_COLORS.choose(rng).unwrap().to_string(),
+        ("ecl", false) => ["zzz", "gmt", "xry"].choose(rng).unwrap().to_string(),
+        ("pid", valid) => {
+            let length = if valid {
+                9
+            } else {
+                *[8, 10].choose(rng).unwrap()
+            };
+            (0..length)
+                .map(|_| rng.gen_range(0..10).to_string())
+                .collect()
+        }
+        _ => rng.gen_range(100..350).to_string(),
+    }
+}
@@ -0,0 +1,35 @@
+//! Boarding passes for a run of consecutive seats but one, which is yours.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 5;
+pub const SIZE: &str = "boarding passes, from 2 to 1022";
+pub const DEFAULT_SIZE: usize = 800;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(2, 1022);
+    let first = rng.gen_range(0..1024 - size);
+    let yours = first + rng.gen_range(1..=size - 1);
+
+    let mut seats: Vec<usize> = (first..=first + size)
+        .filter(|seat| *seat != yours)
+        .collect();
+    seats.shuffle(rng);
+
+    lines(seats.into_iter().map(|seat| {
+        (0..10)
+            .rev()
+            .map(|bit| {
+                let set = seat & (1 << bit) != 0;
+                match (bit >= 3, set) {
+                    (true, false) => 'F',
+                    (true, true) => 'B',
+                    (false, false) => 'L',
+                    (false, true) => 'R',
+                }
+            })
+            .collect::<String>()
+    }))
+}
@@ -0,0 +1,33 @@
+//! Groups of customs declarations. People in a group tend to share some answers.
+
+use rand::Rng as _;
+
+use crate::Rng;
+
+pub const DAY: u8 = 6;
+pub const SIZE: &str = "groups";
+pub const DEFAULT_SIZE: usize = 480;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let groups: Vec<String> = (0..size)
+        .map(|_| {
+            let shared: Vec<bool> = (0..26).map(|_| rng.gen_bool(0.15)).collect();
+
+            (0..rng.gen_range(1..=5))
+                .map(|_| {
+                    let mut answers: String = (b'a'..=b'z')
+                        .zip(&shared)
+                        .filter(|(_, shared)| **shared || rng.gen_bool(0.2))
+                        .map(|(question, _)| question as char)
+                        .collect();
+                    if answers.is_empty() {
+                        answers.push(rng.gen_range(b'a'..=b'z') as char);
+                    }
+                    answers + "\n"
+                })
+                .collect()
+        })
+        .collect();
+
+    groups.join("\n")
+}
@@ -0,0 +1,109 @@
+//! Bag rules. Bags are laid out in levels and only contain bags from lower
+//! levels, which keeps the rules free of cycles and the bag counts of part 2
+//! from overflowing. `shiny gold` is always somewhere in the middle.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 7;
+pub const SIZE: &str = "bag colors, from 8 to 1000";
+pub const DEFAULT_SIZE: usize = 594;
+
+const LEVELS: usize = 8;
+
+const ADJECTIVES: [&str; 25] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "hazy", "bold",
+    "soft", "deep", "frosted", "glossy", "matte",
+];
+
+const COLORS: [&str; 40] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+    "amber",
+    "ivory",
+    "jade",
+    "khaki",
+    "lilac",
+    "mauve",
+    "ochre",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .filter(|name| name != "shiny gold")
+        .collect();
+    names.sort();
+    names.dedup();
+    names.shuffle(rng);
+    names.truncate(size.clamp(LEVELS, 1000) - 1);
+
+    let shiny_gold = rng.gen_range(names.len() / 3..=names.len() / 2);
+    names.insert(shiny_gold, "shiny gold".into());
+
+    let level = |bag: usize| bag * LEVELS / names.len();
+
+    lines((0..names.len()).map(|bag| {
+        let lower: Vec<usize> = (bag + 1..names.len())
+            .filter(|other| level(*other) == level(bag) + 1)
+            .collect();
+
+        let contents: Vec<String> = if lower.is_empty() || rng.gen_bool(0.1) {
+            vec![]
+        } else {
+            let count = rng.gen_range(1..=4);
+            lower
+                .choose_multiple(rng, count)
+                .map(|other| {
+                    let count = rng.gen_range(1..=5);
+                    let plural = if count == 1 { "bag" } else { "bags" };
+                    format!("{} {} {}", count, names[*other], plural)
+                })
+                .collect()
+        };
+
+        if contents.is_empty() {
+            format!("{} bags contain no other bags.", names[bag])
+        } else {
+            format!("{} bags contain {}.", names[bag], contents.join(", "))
+        }
+    }))
+}
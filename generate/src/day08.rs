@@ -0,0 +1,107 @@
+//! Boot code stuck in a loop that exactly one changed `jmp` or `nop` fixes,
+//! as the puzzle promises.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 8;
+pub const SIZE: &str = "instructions, at least 4";
+pub const DEFAULT_SIZE: usize = 600;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+type Instruction = (Operation, isize);
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(4);
+
+    loop {
+        // Forward jumps only, so the fixed code always ends
+        let mut code: Vec<Instruction> = (0..size)
+            .map(|i| match rng.gen_range(0..10) {
+                0..=4 => (Operation::Acc, rng.gen_range(-50..=50)),
+                5..=7 if i + 2 <= size => {
+                    let max = 10.min(size - i) as isize;
+                    (Operation::Jmp, rng.gen_range(2..=max))
+                }
+                _ => (Operation::Nop, rng.gen_range(-100..=100)),
+            })
+            .collect();
+
+        let path = run(&code).expect("Code with forward jumps only always ends");
+
+        // Breaking a `nop` on the path into a backwards `jmp` makes the loop
+        let candidates: Vec<usize> = path
+            .iter()
+            .copied()
+            .filter(|i| *i > 0 && code[*i].0 == Operation::Nop)
+            .collect();
+        let broken = match candidates.choose(rng) {
+            Some(broken) => *broken,
+            None => continue,
+        };
+        let earlier: Vec<&usize> = path.iter().take_while(|i| **i != broken).collect();
+        let target = **earlier.choose(rng).unwrap();
+        code[broken] = (Operation::Jmp, target as isize - broken as isize);
+
+        if fixes(&code) == 1 {
+            return lines(code.iter().map(|(operation, argument)| {
+                let name = match operation {
+                    Operation::Acc => "acc",
+                    Operation::Jmp => "jmp",
+                    Operation::Nop => "nop",
+                };
+                format!("{} {:+}", name, argument)
+            }));
+        }
+    }
+}
+
+/// The instructions run until the code ends, or `None` if it loops.
+fn run(code: &[Instruction]) -> Option<Vec<usize>> {
+    let mut visited = vec![false; code.len()];
+    let mut path = vec![];
+    let mut i = 0;
+
+    while i < code.len() {
+        if visited[i] {
+            return None;
+        }
+        visited[i] = true;
+        path.push(i);
+
+        i = match code[i] {
+            (Operation::Jmp, offset) => {
+                let target = i as isize + offset;
+                if target < 0 {
+                    return None;
+                }
+                target as usize
+            }
+            _ => i + 1,
+        };
+    }
+
+    Some(path)
+}
+
+/// How many single `jmp`/`nop` swaps make the code end.
+fn fixes(code: &[Instruction]) -> usize {
+    (0..code.len())
+        .filter(|i| {
+            let mut fixed = code.to_vec();
+            fixed[*i].0 = match fixed[*i].0 {
+                Operation::Jmp => Operation::Nop,
+                Operation::Nop => Operation::Jmp,
+                Operation::Acc => return false,
+            };
+            run(&fixed).is_some()
+        })
+        .count()
+}
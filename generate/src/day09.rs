@@ -0,0 +1,66 @@
+//! XMAS streams with a 25 number preamble, one number that isn't the sum of two
+//! of the 25 before it, and a contiguous run of earlier numbers adding up to it.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 9;
+pub const SIZE: &str = "numbers, from 30 to 1000";
+pub const DEFAULT_SIZE: usize = 1000;
+
+const PREAMBLE: usize = 25;
+
+/// Every number is the sum of two of the 25 before it, so numbers at least
+/// double every 25 of them. Adding up only the smallest ones keeps a stream of
+/// 1000 numbers below 10^15.
+const SMALLEST: usize = 6;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(30, 1000);
+    let invalid_at = rng.gen_range((size / 2).max(PREAMBLE + 3)..size);
+
+    let mut numbers: Vec<u64> = vec![];
+    while numbers.len() < PREAMBLE {
+        let n = rng.gen_range(1..=50);
+        if !numbers.contains(&n) {
+            numbers.push(n);
+        }
+    }
+
+    while numbers.len() < size {
+        let next = if numbers.len() == invalid_at {
+            invalid_number(rng, &numbers)
+        } else {
+            let mut smallest = numbers[numbers.len() - PREAMBLE..].to_vec();
+            smallest.sort_unstable();
+            smallest.dedup();
+            smallest.truncate(SMALLEST);
+
+            let mut pair = smallest.choose_multiple(rng, 2);
+            pair.next().unwrap() + pair.next().unwrap()
+        };
+
+        numbers.push(next);
+    }
+
+    lines(numbers)
+}
+
+/// A sum of a contiguous run of `numbers`, but not of any two of the last 25.
+fn invalid_number(rng: &mut Rng, numbers: &[u64]) -> u64 {
+    let window = &numbers[numbers.len() - PREAMBLE..];
+
+    loop {
+        let length = rng.gen_range(2..=17.min(numbers.len() - 1));
+        let start = rng.gen_range(0..numbers.len() - length);
+        let sum = numbers[start..start + length].iter().sum();
+
+        let is_pair_sum = window
+            .iter()
+            .any(|a| window.iter().any(|b| a != b && a + b == sum));
+        if !is_pair_sum {
+            return sum;
+        }
+    }
+}
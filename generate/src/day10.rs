@@ -0,0 +1,83 @@
+//! Adapter bags, with joltages 1 or 3 apart like in the real inputs. Long runs
+//! of adapters 1 apart are avoided once the number of arrangements gets close
+//! to overflowing.
+//!
+//! The edge cases also have adapters 2 jolts apart, and now and then a gap of
+//! more than 3 jolts no adapter can bridge.
+
+use std::collections::HashMap;
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 10;
+pub const SIZE: &str = "adapters";
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Arrangements of a run of adapters 1 jolt apart, by the run's length.
+const ARRANGEMENTS: [f64; 5] = [1.0, 1.0, 2.0, 4.0, 7.0];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut joltages = vec![];
+    let mut joltage = 0;
+    let mut run = 0;
+    let mut arrangements = 1.0;
+
+    while joltages.len() < size {
+        let room = arrangements * ARRANGEMENTS[4] < 1e15;
+        let step = if run < 4 && room && rng.gen_bool(0.6) {
+            1
+        } else {
+            3
+        };
+
+        if step == 1 {
+            run += 1;
+        } else {
+            arrangements *= ARRANGEMENTS[run];
+            run = 0;
+        }
+
+        joltage += step;
+        joltages.push(joltage);
+    }
+
+    joltages.shuffle(rng);
+    lines(joltages)
+}
+
+pub fn edge_cases(rng: &mut Rng, size: usize) -> String {
+    // Arrangements up to each joltage, 1 for the outlet
+    let mut arrangements = HashMap::from([(0usize, 1u64)]);
+    let mut joltages = vec![];
+    let mut joltage = 0;
+
+    while joltages.len() < size {
+        // About one gap too wide per input
+        let step = if rng.gen_bool(1.0 / size as f64) {
+            rng.gen_range(4..=6)
+        } else {
+            rng.gen_range(1..=3)
+        };
+        let up_to = |joltage: usize| -> u64 {
+            (joltage.saturating_sub(3)..joltage)
+                .filter_map(|from| arrangements.get(&from))
+                .sum()
+        };
+
+        // A step of 3 or more never adds arrangements
+        let step = if step < 3 && up_to(joltage + step) > 1_000_000_000_000_000 {
+            3
+        } else {
+            step
+        };
+
+        joltage += step;
+        arrangements.insert(joltage, up_to(joltage));
+        joltages.push(joltage);
+    }
+
+    joltages.shuffle(rng);
+    lines(joltages)
+}
@@ -0,0 +1,82 @@
+//! Square seat layouts of empty seats and floor. Random layouts don't always
+//! settle like the real ones do, some end up flipping between two states
+//! forever, so only layouts that settle under both parts' rules are kept.
+
+use crate::{grid, Rng};
+
+pub const DAY: u8 = 11;
+pub const SIZE: &str = "rows and columns";
+pub const DEFAULT_SIZE: usize = 95;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let layout = grid(rng, size, size, 'L', '.', 0.8);
+        let seats: Vec<Vec<char>> = layout.lines().map(|row| row.chars().collect()).collect();
+
+        if settles(seats.clone(), 4, false) && settles(seats, 5, true) {
+            return layout;
+        }
+    }
+}
+
+/// Whether people stop moving around, leaving seats with `tolerance` or more
+/// occupied neighbours, or with sight, seats they can see.
+fn settles(mut seats: Vec<Vec<char>>, tolerance: usize, sight: bool) -> bool {
+    let mut previous = None;
+
+    loop {
+        let next: Vec<Vec<char>> = (0..seats.len())
+            .map(|row| {
+                (0..seats[row].len())
+                    .map(|column| {
+                        let occupied = occupied_neighbours(&seats, row, column, sight);
+                        match seats[row][column] {
+                            'L' if occupied == 0 => '#',
+                            '#' if occupied >= tolerance => 'L',
+                            seat => seat,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if next == seats {
+            return true;
+        }
+        if previous.as_ref() == Some(&next) {
+            return false;
+        }
+
+        previous = Some(seats);
+        seats = next;
+    }
+}
+
+fn occupied_neighbours(seats: &[Vec<char>], row: usize, column: usize, sight: bool) -> usize {
+    let mut occupied = 0;
+
+    for dy in -1..=1isize {
+        for dx in -1..=1isize {
+            if (dy, dx) == (0, 0) {
+                continue;
+            }
+
+            let (mut y, mut x) = (row as isize + dy, column as isize + dx);
+            // Negative coordinates wrap around to ones way out of bounds
+            while let Some(seat) = seats.get(y as usize).and_then(|row| row.get(x as usize)) {
+                match seat {
+                    '#' => occupied += 1,
+                    '.' if sight => {
+                        y += dy;
+                        x += dx;
+                        continue;
+                    }
+                    _ => {}
+                }
+                break;
+            }
+        }
+    }
+
+    occupied
+}
@@ -0,0 +1,22 @@
+//! Navigation instructions. Turns are always by multiples of 90 degrees.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 12;
+pub const SIZE: &str = "instructions";
+pub const DEFAULT_SIZE: usize = 780;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let action = *b"NSEWLRF".choose(rng).unwrap() as char;
+        let value = match action {
+            'L' | 'R' => 90 * rng.gen_range(1..=3),
+            'F' => rng.gen_range(1..=100),
+            _ => rng.gen_range(1..=5),
+        };
+
+        format!("{}{}", action, value)
+    }))
+}
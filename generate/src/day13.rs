@@ -0,0 +1,44 @@
+//! Bus notes. Bus ids are distinct primes, so part 2 always has an answer, and
+//! their product stays well within an `i64`.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Rng;
+
+pub const DAY: u8 = 13;
+pub const SIZE: &str = "entries in the bus list, at least 2";
+pub const DEFAULT_SIZE: usize = 60;
+
+const SMALL_PRIMES: [u64; 11] = [7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43];
+const LARGE_PRIMES: [u64; 10] = [353, 419, 431, 443, 463, 523, 557, 601, 643, 733];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let count = (size / 7).clamp(2, 9);
+
+    let mut ids: Vec<u64> = LARGE_PRIMES
+        .choose_multiple(rng, 2.min(count - 1))
+        .copied()
+        .collect();
+    let mut small = SMALL_PRIMES.to_vec();
+    small.shuffle(rng);
+    for id in small {
+        if ids.len() < count && ids.iter().product::<u64>() * id < 1 << 53 {
+            ids.push(id);
+        }
+    }
+
+    // The first bus is always in service, the rest anywhere in the list
+    let mut slots: Vec<usize> = (1..size).collect();
+    slots.shuffle(rng);
+    let mut schedule = vec!["x".to_string(); size];
+    for (id, slot) in ids.iter().zip(std::iter::once(0).chain(slots)) {
+        schedule[slot] = id.to_string();
+    }
+
+    format!(
+        "{}\n{}\n",
+        rng.gen_range(100_000..10_000_000),
+        schedule.join(",")
+    )
+}
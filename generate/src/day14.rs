@@ -0,0 +1,36 @@
+//! Initialization programs. Masks have at most 9 floating bits, like the real
+//! ones, so part 2 writes at most 512 addresses per instruction.
+
+use rand::{seq::index, Rng as _};
+
+use crate::Rng;
+
+pub const DAY: u8 = 14;
+pub const SIZE: &str = "instructions";
+pub const DEFAULT_SIZE: usize = 560;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut program = String::new();
+    let mut instructions = 0;
+
+    while instructions < size {
+        let mut mask: Vec<char> = (0..36).map(|_| if rng.gen() { '1' } else { '0' }).collect();
+        let floating = rng.gen_range(3..=9);
+        for bit in index::sample(rng, 36, floating) {
+            mask[bit] = 'X';
+        }
+        program += &format!("mask = {}\n", mask.iter().collect::<String>());
+        instructions += 1;
+
+        for _ in 0..rng.gen_range(1..=6).min(size - instructions) {
+            program += &format!(
+                "mem[{}] = {}\n",
+                rng.gen_range(0..65536),
+                rng.gen_range(0..1u64 << 30)
+            );
+            instructions += 1;
+        }
+    }
+
+    program
+}
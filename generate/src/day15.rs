@@ -0,0 +1,18 @@
+//! Starting numbers for the memory game, all different.
+
+use rand::seq::index;
+
+use crate::Rng;
+
+pub const DAY: u8 = 15;
+pub const SIZE: &str = "starting numbers, from 1 to 20";
+pub const DEFAULT_SIZE: usize = 7;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let numbers: Vec<String> = index::sample(rng, 20, size.clamp(1, 20))
+        .into_iter()
+        .map(|n| n.to_string())
+        .collect();
+
+    numbers.join(",") + "\n"
+}
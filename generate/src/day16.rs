@@ -0,0 +1,116 @@
+//! Ticket notes whose fields can be told apart by elimination, as part 2
+//! expects: sorted by how many positions they fit, each field fits exactly one
+//! more position than the one before it. Some nearby tickets have a value that
+//! fits no field at all.
+//!
+//! The edge cases have at least 2 fields, and half the time two of them fit the
+//! same positions, so there's no telling which is which.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::Rng;
+
+pub const DAY: u8 = 16;
+pub const SIZE: &str = "fields, at least 1";
+pub const DEFAULT_SIZE: usize = 20;
+
+const TICKETS: usize = 240;
+
+const NAMES: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    notes(rng, size.max(1), None)
+}
+
+pub fn edge_cases(rng: &mut Rng, size: usize) -> String {
+    let fields = size.max(2);
+    let ambiguous = if rng.gen() {
+        Some(rng.gen_range(0..fields - 1))
+    } else {
+        None
+    };
+
+    notes(rng, fields, ambiguous)
+}
+
+/// Notes with `fields` fields. If `ambiguous` is some field, it fits as many
+/// positions as the field after it.
+fn notes(rng: &mut Rng, fields: usize, ambiguous: Option<usize>) -> String {
+    // Values in a position of a valid ticket come from its own band: 100 * band + 10..=50
+    let band = |rng: &mut Rng, band: usize| 100 * band + rng.gen_range(10..=50);
+
+    let names: Vec<String> = (0..fields)
+        .map(|i| match NAMES.get(i) {
+            Some(name) => name.to_string(),
+            None => format!("field {}", i + 1),
+        })
+        .collect();
+
+    // Field `i` fits bands 0 to `i`, plus a small range past the last band no
+    // value ever comes from
+    let past_bands = 100 * fields;
+    let mut rules: Vec<String> = (0..fields)
+        .map(|i| {
+            let extra = past_bands + 10 * i;
+            let last_band = if ambiguous == Some(i) { i + 1 } else { i };
+            format!(
+                "{}: {}-{} or {}-{}",
+                names[i],
+                rng.gen_range(1..10),
+                100 * last_band + rng.gen_range(50..=60),
+                extra,
+                extra + 5
+            )
+        })
+        .collect();
+    rules.shuffle(rng);
+
+    // Which band shows up in each position of the tickets
+    let mut bands: Vec<usize> = (0..fields).collect();
+    bands.shuffle(rng);
+
+    let ticket = |rng: &mut Rng| -> String {
+        let mut values: Vec<usize> = bands.iter().map(|b| band(rng, *b)).collect();
+        if rng.gen_bool(0.2) {
+            // Below every rule, or in the gap between a rule's two ranges
+            values[rng.gen_range(0..fields)] = if rng.gen() {
+                0
+            } else {
+                past_bands - rng.gen_range(1..=10)
+            };
+        }
+        let values: Vec<String> = values.iter().map(usize::to_string).collect();
+        values.join(",")
+    };
+
+    let yours: Vec<String> = bands.iter().map(|b| band(rng, *b).to_string()).collect();
+    let nearby: Vec<String> = (0..TICKETS).map(|_| ticket(rng)).collect();
+
+    format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}\n",
+        rules.join("\n"),
+        yours.join(","),
+        nearby.join("\n")
+    )
+}
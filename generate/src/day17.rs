@@ -0,0 +1,11 @@
+//! Square starting slices of the pocket dimension.
+
+use crate::{grid, Rng};
+
+pub const DAY: u8 = 17;
+pub const SIZE: &str = "rows and columns";
+pub const DEFAULT_SIZE: usize = 8;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    grid(rng, size, size, '#', '.', 0.5)
+}
@@ -0,0 +1,95 @@
+//! Homework expressions, nested up to two parentheses deep. Expressions whose
+//! value under either part's rules would get too large are thrown away, so the
+//! sum of all of them fits in an `i64`.
+
+use rand::Rng as _;
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 18;
+pub const SIZE: &str = "expressions";
+pub const DEFAULT_SIZE: usize = 370;
+
+const MAX_VALUE: u64 = 1 << 40;
+
+enum Operand {
+    Number(u64),
+    Group(Expression),
+}
+
+/// Operands with the operator before each one but the first, `true` for `+`.
+struct Expression(Vec<(bool, Operand)>);
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| loop {
+        let expression = expression(rng, 2);
+        let fits = |value: Option<u64>| value.is_some_and(|value| value <= MAX_VALUE);
+        if fits(expression.value(false)) && fits(expression.value(true)) {
+            break expression.to_string();
+        }
+    }))
+}
+
+fn expression(rng: &mut Rng, depth: usize) -> Expression {
+    Expression(
+        (0..rng.gen_range(2..=6))
+            .map(|_| {
+                let operand = if depth > 0 && rng.gen_bool(0.25) {
+                    Operand::Group(expression(rng, depth - 1))
+                } else {
+                    Operand::Number(rng.gen_range(1..=9))
+                };
+                (rng.gen(), operand)
+            })
+            .collect(),
+    )
+}
+
+impl Operand {
+    fn value(&self, addition_first: bool) -> Option<u64> {
+        match self {
+            Operand::Number(n) => Some(*n),
+            Operand::Group(expression) => expression.value(addition_first),
+        }
+    }
+}
+
+impl Expression {
+    /// Left to right for part 1, additions before multiplications for part 2.
+    /// `None` if it overflows along the way.
+    fn value(&self, addition_first: bool) -> Option<u64> {
+        let mut product = 1u64;
+        let mut sum = 0u64;
+
+        for (i, (addition, operand)) in self.0.iter().enumerate() {
+            let value = operand.value(addition_first)?;
+
+            if i == 0 || *addition {
+                sum = sum.checked_add(value)?;
+            } else if addition_first {
+                product = product.checked_mul(sum)?;
+                sum = value;
+            } else {
+                sum = sum.checked_mul(value)?;
+            }
+        }
+
+        product.checked_mul(sum)
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, (addition, operand)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " {} ", if *addition { '+' } else { '*' })?;
+            }
+            match operand {
+                Operand::Number(n) => write!(f, "{}", n)?,
+                Operand::Group(expression) => write!(f, "({})", expression)?,
+            }
+        }
+
+        Ok(())
+    }
+}
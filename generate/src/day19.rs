@@ -0,0 +1,114 @@
+//! Message rules shaped like the real ones: `0: 8 11`, `8: 42`, `11: 42 31`,
+//! with rules 42 and 31 built out of layers of other rules, all matching
+//! messages of the same length. About half the messages match rule 0 with the
+//! loops of part 2 in place, the rest are random.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 19;
+pub const SIZE: &str = "messages";
+pub const DEFAULT_SIZE: usize = 400;
+
+/// Each layer of rules matches messages twice as long as the one below.
+const LAYERS: usize = 3;
+const RULES_PER_LAYER: usize = 6;
+
+enum Body {
+    Letter(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<usize> = (1..50).filter(|n| ![8, 11, 31, 42].contains(n)).collect();
+    numbers.shuffle(rng);
+    let mut numbers = numbers.into_iter();
+
+    let mut rules: Vec<(usize, Body)> = vec![
+        (0, Body::Alternatives(vec![vec![8, 11]])),
+        (8, Body::Alternatives(vec![vec![42]])),
+        (11, Body::Alternatives(vec![vec![42, 31]])),
+    ];
+
+    let mut layer: Vec<usize> = vec![];
+    for letter in &['a', 'b'] {
+        let number = numbers.next().unwrap();
+        rules.push((number, Body::Letter(*letter)));
+        layer.push(number);
+    }
+
+    for i in 1..=LAYERS {
+        let layer_numbers: Vec<usize> = if i == LAYERS {
+            vec![42, 31]
+        } else {
+            numbers.by_ref().take(RULES_PER_LAYER).collect()
+        };
+
+        for number in &layer_numbers {
+            let alternatives = (0..rng.gen_range(1..=2))
+                .map(|_| vec![*layer.choose(rng).unwrap(), *layer.choose(rng).unwrap()])
+                .collect();
+            rules.push((*number, Body::Alternatives(alternatives)));
+        }
+
+        layer = layer_numbers;
+    }
+
+    let length = 1 << LAYERS;
+    let messages: Vec<String> = (0..size)
+        .map(|_| {
+            if rng.gen() {
+                // Part 2 matches 42 n times followed by 31 fewer than n times
+                let thirty_ones = rng.gen_range(1..=2);
+                let forty_twos = rng.gen_range(thirty_ones + 1..=4);
+                let mut message = String::new();
+                for _ in 0..forty_twos {
+                    expand(rng, &rules, 42, &mut message);
+                }
+                for _ in 0..thirty_ones {
+                    expand(rng, &rules, 31, &mut message);
+                }
+                message
+            } else {
+                (0..length * rng.gen_range(3..=6))
+                    .map(|_| if rng.gen() { 'a' } else { 'b' })
+                    .collect()
+            }
+        })
+        .collect();
+
+    rules.shuffle(rng);
+    let rules = rules.iter().map(|(number, body)| {
+        let body = match body {
+            Body::Letter(letter) => format!("\"{}\"", letter),
+            Body::Alternatives(alternatives) => {
+                let alternatives: Vec<String> = alternatives
+                    .iter()
+                    .map(|sequence| {
+                        let sequence: Vec<String> = sequence.iter().map(usize::to_string).collect();
+                        sequence.join(" ")
+                    })
+                    .collect();
+                alternatives.join(" | ")
+            }
+        };
+        format!("{}: {}", number, body)
+    });
+
+    format!("{}\n{}", lines(rules), lines(messages))
+}
+
+/// Appends a random message matching rule `number` to `message`.
+fn expand(rng: &mut Rng, rules: &[(usize, Body)], number: usize, message: &mut String) {
+    let (_, body) = rules.iter().find(|(n, _)| *n == number).unwrap();
+
+    match body {
+        Body::Letter(letter) => message.push(*letter),
+        Body::Alternatives(alternatives) => {
+            for rule in alternatives.choose(rng).unwrap() {
+                expand(rng, rules, *rule, message);
+            }
+        }
+    }
+}
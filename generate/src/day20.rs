@@ -0,0 +1,154 @@
+//! Camera tiles cut out of a random image with sea monsters in it, then
+//! rotated, flipped and shuffled. Tiles next to each other share their border,
+//! and no other two borders match in any orientation, like in the real input.
+
+use std::collections::HashSet;
+
+use rand::{seq::index, seq::SliceRandom, Rng as _};
+
+use crate::Rng;
+
+pub const DAY: u8 = 20;
+pub const SIZE: &str = "tiles per side of the image, from 3 to 12";
+pub const DEFAULT_SIZE: usize = 12;
+
+const SEA_MONSTER: [&str; 3] = [
+    "..................#.",
+    "#....##....##....###",
+    ".#..#..#..#..#..#...",
+];
+
+type Pixels = Vec<Vec<bool>>;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let n = size.clamp(3, 12);
+    let image = image(rng, 8 * n);
+
+    // The whole picture, borders included, with each border shared by the two
+    // tiles on either side of it
+    let side = 9 * n + 1;
+    let mut pixels = vec![vec![false; side]; side];
+    for (row, image_row) in image.iter().enumerate() {
+        for (column, pixel) in image_row.iter().enumerate() {
+            pixels[row / 8 * 9 + 1 + row % 8][column / 8 * 9 + 1 + column % 8] = *pixel;
+        }
+    }
+    for row in (0..side).step_by(9) {
+        for column in (0..side).step_by(9) {
+            pixels[row][column] = rng.gen();
+        }
+    }
+
+    let mut used_borders = HashSet::new();
+    for a in 0..=n {
+        for b in 0..n {
+            // Horizontal, then vertical border segments between two corners
+            for &horizontal in &[true, false] {
+                let cell = |k: usize| {
+                    if horizontal {
+                        (9 * a, 9 * b + k)
+                    } else {
+                        (9 * b + k, 9 * a)
+                    }
+                };
+
+                loop {
+                    for k in 1..=8 {
+                        let (row, column) = cell(k);
+                        pixels[row][column] = rng.gen();
+                    }
+                    let border: Vec<bool> = (0..=9)
+                        .map(|k| {
+                            let (row, column) = cell(k);
+                            pixels[row][column]
+                        })
+                        .collect();
+                    let reversed: Vec<bool> = border.iter().rev().copied().collect();
+
+                    if border != reversed && !used_borders.contains(&border) {
+                        used_borders.insert(reversed);
+                        used_borders.insert(border);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    let ids = index::sample(rng, 9000, n * n);
+    let mut tiles: Vec<String> = (0..n * n)
+        .map(|i| {
+            let (row, column) = (i / n * 9, i % n * 9);
+            let mut tile: Pixels = pixels[row..=row + 9]
+                .iter()
+                .map(|pixels| pixels[column..=column + 9].to_vec())
+                .collect();
+
+            for _ in 0..rng.gen_range(0..4) {
+                tile = rotate(&tile);
+            }
+            if rng.gen() {
+                tile.reverse();
+            }
+
+            let rows: String = tile
+                .iter()
+                .map(|row| {
+                    let mut row: String = row.iter().map(|p| if *p { '#' } else { '.' }).collect();
+                    row.push('\n');
+                    row
+                })
+                .collect();
+            format!("Tile {}:\n{}", 1000 + ids.index(i), rows)
+        })
+        .collect();
+    tiles.shuffle(rng);
+
+    tiles.join("\n")
+}
+
+/// A random `side` by `side` image with a few sea monsters, which never overlap.
+fn image(rng: &mut Rng, side: usize) -> Pixels {
+    let mut image: Pixels = (0..side)
+        .map(|_| (0..side).map(|_| rng.gen_bool(0.3)).collect())
+        .collect();
+
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(column, _)| (row, column))
+        })
+        .collect();
+
+    let mut taken = HashSet::new();
+    for _ in 0..side * side / 128 {
+        let row = rng.gen_range(0..=side - SEA_MONSTER.len());
+        let column = rng.gen_range(0..=side - SEA_MONSTER[0].len());
+        let cells: Vec<(usize, usize)> =
+            monster.iter().map(|(r, c)| (row + r, column + c)).collect();
+
+        if cells.iter().all(|cell| !taken.contains(cell)) {
+            for (r, c) in cells {
+                image[r][c] = true;
+                taken.insert((r, c));
+            }
+        }
+    }
+
+    image
+}
+
+/// Quarter turn clockwise.
+fn rotate(pixels: &Pixels) -> Pixels {
+    let side = pixels.len();
+    (0..side)
+        .map(|row| {
+            (0..side)
+                .map(|column| pixels[side - 1 - column][row])
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+//! Food lists where every allergen can be pinned to a single ingredient by
+//! elimination, as part 2 expects. There are more foods than asked for when
+//! that takes more of them.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 21;
+pub const SIZE: &str = "foods, at least 2";
+pub const DEFAULT_SIZE: usize = 40;
+
+const ALLERGENS: [&str; 8] = [
+    "dairy",
+    "eggs",
+    "fish",
+    "nuts",
+    "peanuts",
+    "sesame",
+    "shellfish",
+    "soy",
+];
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut ingredients: Vec<String> = (0..200)
+        .map(|_| word(rng))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    ingredients.shuffle(rng);
+    let allergens = &ALLERGENS[..rng.gen_range(3..=ALLERGENS.len())];
+    let (unsafe_ingredients, safe_ingredients) = ingredients.split_at(allergens.len());
+
+    let mut foods: Vec<(Vec<&str>, Vec<&str>)> = vec![];
+    while foods.len() < size.max(2) || !resolves(&foods, allergens.len()) {
+        let count = rng.gen_range(5..=20);
+        let mut food: Vec<&str> = safe_ingredients
+            .choose_multiple(rng, count)
+            .map(String::as_str)
+            .collect();
+        let mut listed = vec![];
+        for (ingredient, allergen) in unsafe_ingredients.iter().zip(allergens) {
+            if rng.gen_bool(0.5) {
+                food.push(ingredient);
+                // Allergens aren't always listed
+                if rng.gen_bool(0.7) {
+                    listed.push(*allergen);
+                }
+            }
+        }
+        // Foods list at least one allergen though
+        if listed.is_empty() {
+            let i = rng.gen_range(0..allergens.len());
+            if !food.contains(&unsafe_ingredients[i].as_str()) {
+                food.push(&unsafe_ingredients[i]);
+            }
+            listed.push(allergens[i]);
+        }
+        food.shuffle(rng);
+        foods.push((food, listed));
+    }
+
+    lines(
+        foods.iter().map(|(food, allergens)| {
+            format!("{} (contains {})", food.join(" "), allergens.join(", "))
+        }),
+    )
+}
+
+/// Whether every allergen appears, and elimination tells which ingredient has each.
+fn resolves(foods: &[(Vec<&str>, Vec<&str>)], allergens: usize) -> bool {
+    let mut candidates: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (ingredients, listed) in foods {
+        for allergen in listed {
+            let ingredients: BTreeSet<&str> = ingredients.iter().copied().collect();
+            let candidates = candidates
+                .entry(allergen)
+                .or_insert_with(|| ingredients.clone());
+            *candidates = candidates.intersection(&ingredients).copied().collect();
+        }
+    }
+
+    if candidates.len() != allergens {
+        return false;
+    }
+
+    while let Some((allergen, ingredient)) = candidates
+        .iter()
+        .find(|(_, ingredients)| ingredients.len() == 1)
+        .map(|(allergen, ingredients)| (*allergen, *ingredients.iter().next().unwrap()))
+    {
+        candidates.remove(allergen);
+        for ingredients in candidates.values_mut() {
+            ingredients.remove(ingredient);
+        }
+    }
+
+    candidates.is_empty()
+}
+
+fn word(rng: &mut Rng) -> String {
+    (0..rng.gen_range(4..=8))
+        .map(|_| rng.gen_range(b'a'..=b'z') as char)
+        .collect()
+}
@@ -0,0 +1,52 @@
+//! Two decks splitting the cards from 1 to `size` between them. Some deals
+//! make a game of plain Combat go round in circles forever, which the real
+//! ones never do, so those are dealt again.
+
+use std::collections::{HashSet, VecDeque};
+
+use rand::seq::SliceRandom;
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 22;
+pub const SIZE: &str = "cards, at least 2";
+pub const DEFAULT_SIZE: usize = 50;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut cards: Vec<usize> = (1..=size.max(2)).collect();
+
+    loop {
+        cards.shuffle(rng);
+        let (player1, player2) = cards.split_at(cards.len() / 2);
+
+        if ends(player1, player2) {
+            return format!(
+                "Player 1:\n{}\nPlayer 2:\n{}",
+                lines(player1),
+                lines(player2)
+            );
+        }
+    }
+}
+
+/// Whether a game of plain Combat between the two decks has a winner.
+fn ends(player1: &[usize], player2: &[usize]) -> bool {
+    let mut player1: VecDeque<usize> = player1.iter().copied().collect();
+    let mut player2: VecDeque<usize> = player2.iter().copied().collect();
+    let mut seen = HashSet::new();
+
+    while !player1.is_empty() && !player2.is_empty() {
+        if !seen.insert((player1.clone(), player2.clone())) {
+            return false;
+        }
+
+        let (card1, card2) = (player1.pop_front().unwrap(), player2.pop_front().unwrap());
+        if card1 > card2 {
+            player1.extend([card1, card2]);
+        } else {
+            player2.extend([card2, card1]);
+        }
+    }
+
+    true
+}
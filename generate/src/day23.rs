@@ -0,0 +1,16 @@
+//! Cup labels, from 1 to `size` in any order.
+
+use rand::seq::SliceRandom;
+
+use crate::Rng;
+
+pub const DAY: u8 = 23;
+pub const SIZE: &str = "cups, from 5 to 9";
+pub const DEFAULT_SIZE: usize = 9;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut labels: Vec<String> = (1..=size.clamp(5, 9)).map(|n| n.to_string()).collect();
+    labels.shuffle(rng);
+
+    labels.concat() + "\n"
+}
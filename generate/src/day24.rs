@@ -0,0 +1,17 @@
+//! Lists of steps to tiles, each step one of `e`, `se`, `sw`, `w`, `nw` or `ne`.
+
+use rand::{seq::SliceRandom, Rng as _};
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 24;
+pub const SIZE: &str = "tiles to flip";
+pub const DEFAULT_SIZE: usize = 400;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        (0..rng.gen_range(10..=20))
+            .map(|_| *["e", "se", "sw", "w", "nw", "ne"].choose(rng).unwrap())
+            .collect::<String>()
+    }))
+}
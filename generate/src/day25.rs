@@ -0,0 +1,29 @@
+//! Card and door public keys, made from loop sizes up to `size`.
+
+use rand::Rng as _;
+
+use crate::{lines, Rng};
+
+pub const DAY: u8 = 25;
+pub const SIZE: &str = "the largest loop size, at least 2";
+pub const DEFAULT_SIZE: usize = 10_000_000;
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut loop_size = || rng.gen_range(1..size.max(2)) as u64;
+    let keys = [transform(7, loop_size()), transform(7, loop_size())];
+
+    lines(keys)
+}
+
+/// `subject` to the power of `loop_size`, modulo 20201227.
+fn transform(subject: u64, loop_size: u64) -> u64 {
+    let (mut result, mut base, mut exponent) = (1, subject, loop_size);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % 20201227;
+        }
+        base = base * base % 20201227;
+        exponent >>= 1;
+    }
+    result
+}
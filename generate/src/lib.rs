@@ -0,0 +1,146 @@
+//! Random puzzle inputs for every day, to stress the solutions with more than
+//! the one input each day comes with. Inputs are reproducible: the same seed
+//! and size always give the same input.
+//!
+//! Every input is well formed, and where the puzzle promises something about
+//! its input (day 9 has an invalid number, day 20's tiles fit together, ...)
+//! so do the generated ones, unless a day's module says otherwise. Some days
+//! also have an edge case generator, for inputs the puzzle rules out but a
+//! solution should still cope with.
+
+use rand::{Rng as _, SeedableRng};
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// The random number generator every generator draws from. Unlike `StdRng`
+/// its output is stable across versions of `rand`, which keeps seeds
+/// reproducible.
+pub type Rng = rand_chacha::ChaCha8Rng;
+
+/// A day's input generator.
+pub struct Generator {
+    pub day: u8,
+    /// What `size` counts for this day.
+    pub size: &'static str,
+    /// A size close to the real puzzle input's.
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+    edge_cases: Option<fn(&mut Rng, usize) -> String>,
+}
+
+impl Generator {
+    pub fn find(day: u8) -> Option<&'static Generator> {
+        GENERATORS.iter().find(|generator| generator.day == day)
+    }
+
+    /// An input of about `size` elements, always the same one for the same `seed`.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::seed_from_u64(seed), size)
+    }
+
+    /// Like `generate`, but the input may break what the puzzle promises about
+    /// it, in the ways the day's module describes. `None` if the day has no
+    /// edge case generator.
+    pub fn generate_edge_cases(&self, seed: u64, size: usize) -> Option<String> {
+        self.edge_cases
+            .map(|edge_cases| edge_cases(&mut Rng::seed_from_u64(seed), size))
+    }
+}
+
+macro_rules! generators {
+    ($($day:ident $(with $edge_cases:ident)?),*) => {
+        /// Every day's generator, in order.
+        pub static GENERATORS: [Generator; 25] = [$(
+            Generator {
+                day: $day::DAY,
+                size: $day::SIZE,
+                default_size: $day::DEFAULT_SIZE,
+                generate: $day::generate,
+                edge_cases: generators!(@edge_cases $day $($edge_cases)?),
+            },
+        )*];
+    };
+    (@edge_cases $day:ident) => { None };
+    (@edge_cases $day:ident $edge_cases:ident) => { Some($day::$edge_cases) };
+}
+
+generators!(
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10 with edge_cases, day11,
+    day12, day13, day14, day15, day16 with edge_cases, day17, day18, day19, day20, day21, day22,
+    day23, day24, day25
+);
+
+/// `width` by `height` characters, each `on` with probability `density`.
+fn grid(rng: &mut Rng, width: usize, height: usize, on: char, off: char, density: f64) -> String {
+    (0..height)
+        .map(|_| {
+            let mut row: String = (0..width)
+                .map(|_| if rng.gen_bool(density) { on } else { off })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+/// One line per item.
+fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string() + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators_are_in_order() {
+        for (i, generator) in GENERATORS.iter().enumerate() {
+            assert_eq!(generator.day as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_input() {
+        for generator in &GENERATORS {
+            assert_eq!(
+                generator.generate(7, generator.default_size),
+                generator.generate(7, generator.default_size),
+                "day {}",
+                generator.day
+            );
+            assert_eq!(
+                generator.generate_edge_cases(7, generator.default_size),
+                generator.generate_edge_cases(7, generator.default_size),
+                "day {}",
+                generator.day
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Times parsing and each part of every day on its puzzle input. Run a single
+//! day with e.g. `cargo bench -p runner -- day15`, and `aoc bench` for a quick
+//! summary table of all of them.
+
+use std::path::Path;
+
+use aoc::{Input, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use runner::Day;
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let day = Day::find(S::DAY).unwrap();
+    let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).join(day.input_path());
+    let input = Input::from_file(&path)
+        .unwrap_or_else(|error| panic!("Unable to read {}: {}", path.display(), error));
+    let solution = S::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    // Some parts take over a second, the default 100 samples would take ages
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| black_box(&solution).part1()));
+    group.bench_function("part2", |b| b.iter(|| black_box(&solution).part2()));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_day::<day01::Day01>,
+    bench_day::<day02::Day02>,
+    bench_day::<day03::Day03>,
+    bench_day::<day04::Day04>,
+    bench_day::<day05::Day05>,
+    bench_day::<day06::Day06>,
+    bench_day::<day07::Day07>,
+    bench_day::<day08::Day08>,
+    bench_day::<day09::Day09>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day::<day14::Day14>,
+    bench_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+    bench_day::<day17::Day17>,
+    bench_day::<day18::Day18>,
+    bench_day::<day19::Day19>,
+    bench_day::<day20::Day20>,
+    bench_day::<day21::Day21>,
+    bench_day::<day22::Day22>,
+    bench_day::<day23::Day23>,
+    bench_day::<day24::Day24>,
+    bench_day::<day25::Day25>,
+);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// The system allocator, also keeping track of how many bytes each thread has
+/// allocated. Install it with `#[global_allocator]` for [`measure`] to report
+/// anything.
+pub struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATED: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn record(bytes: isize) {
+    // Threads being torn down have no locals left, their allocations don't matter
+    let _ = ALLOCATED.try_with(|allocated| {
+        let now = allocated.get() + bytes;
+        allocated.set(now);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f` and returns its result along with the most memory it had allocated
+/// at once, in bytes. Only allocations made on the calling thread count, which
+/// is all of them for a day's solution since none of them spawn threads.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let start = ALLOCATED.with(Cell::get);
+    PEAK.with(|peak| peak.set(start));
+
+    let result = f();
+
+    let peak = PEAK.with(Cell::get);
+    (result, (peak - start).max(0) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_measure() {
+        let (sum, peak) = measure(|| {
+            let small: Vec<u8> = vec![1; 1000];
+            drop(small);
+            let large: Vec<u64> = vec![1; 5000];
+            large.iter().sum::<u64>()
+        });
+
+        assert_eq!(sum, 5000);
+        assert_eq!(peak, 40_000);
+    }
+}
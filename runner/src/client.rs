@@ -0,0 +1,294 @@
+use std::{env, fmt, fs, io, path::Path, path::PathBuf, time::Duration};
+
+use aoc::{Answer, Part};
+
+/// Talks to the Advent of Code website on behalf of one user: downloading
+/// puzzle inputs and submitting answers. The base URL is configurable so tests
+/// can point it at a local server instead.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Wrong, sometimes with a hint such as `too high`.
+    Incorrect {
+        hint: Option<String>,
+    },
+    /// Too soon after a previous answer, with the remaining wait as the site puts it.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, so the answer wasn't checked.
+    AlreadySolved,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token in `AOC_SESSION` nor in the config file.
+    MissingSession(PathBuf),
+    /// The site answered, but with an error status.
+    Status {
+        status: u16,
+        body: String,
+    },
+    /// The request didn't get an answer at all.
+    Transport(String),
+    /// The answer page didn't say anything we recognize.
+    UnexpectedResponse(String),
+    Io(io::Error),
+}
+
+impl Client {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+    pub const YEAR: u16 = 2020;
+
+    pub fn new(session: impl Into<String>) -> Client {
+        Client::with_base_url(Client::BASE_URL, session)
+    }
+
+    pub fn with_base_url(base_url: impl Into<String>, session: impl Into<String>) -> Client {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!(
+                "runner/",
+                env!("CARGO_PKG_VERSION"),
+                " by ",
+                env!("CARGO_PKG_AUTHORS")
+            ))
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Client {
+            base_url: base_url.into().trim_end_matches('/').into(),
+            session: session.into(),
+            agent,
+        }
+    }
+
+    /// A client for the session token from [`session_token`], talking to
+    /// `AOC_BASE_URL` if set and to the real site otherwise.
+    pub fn from_env() -> Result<Client, ClientError> {
+        let session = session_token()?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| Client::BASE_URL.into());
+
+        Ok(Client::with_base_url(base_url, session))
+    }
+
+    /// Downloads the puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(day, "input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// The puzzle input of `day`, read from `path` if it was downloaded before,
+    /// otherwise downloaded and saved there. `refresh` downloads it regardless.
+    pub fn cached_input(&self, day: u8, path: &Path, refresh: bool) -> Result<String, ClientError> {
+        if !refresh && path.is_file() {
+            return Ok(fs::read_to_string(path)?);
+        }
+
+        let input = self.input(day)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, &input)?;
+
+        Ok(input)
+    }
+
+    /// Submits `answer` as the solution to one part of `day`.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Verdict, ClientError> {
+        let response = self
+            .agent
+            .post(&self.url(day, "answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])?;
+
+        Verdict::parse(&response.into_string()?)
+    }
+
+    fn url(&self, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, Client::YEAR, day, page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site returns after submitting an answer.
+    pub fn parse(page: &str) -> Result<Verdict, ClientError> {
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if page.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .iter()
+                .find(|hint| page.contains(&format!("your answer is {}", hint)))
+                .map(|hint| hint.to_string());
+
+            Ok(Verdict::Incorrect { hint })
+        } else if page.contains("You gave an answer too recently") {
+            let wait = between(page, "You have ", " left to wait").map(String::from);
+
+            Ok(Verdict::RateLimited { wait })
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Verdict::AlreadySolved)
+        } else {
+            Err(ClientError::UnexpectedResponse(page.into()))
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect { hint: Some(hint) } => write!(f, "incorrect, {}", hint),
+            Verdict::Incorrect { hint: None } => write!(f, "incorrect"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "answered too recently, try again in {}", wait)
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "answered too recently"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// The session token, from the `AOC_SESSION` environment variable or else the
+/// first line of [`config_path`].
+pub fn session_token() -> Result<String, ClientError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().into());
+        }
+    }
+
+    let path = config_path();
+    fs::read_to_string(&path)
+        .ok()
+        .and_then(|config| config.lines().next().map(|line| line.trim().to_string()))
+        .filter(|session| !session.is_empty())
+        .ok_or(ClientError::MissingSession(path))
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+pub fn config_path() -> PathBuf {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_default();
+
+    config_dir.join("aoc").join("session")
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let to = from + text[from..].find(end)?;
+    Some(&text[from..to])
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession(path) => write!(
+                f,
+                "no session token, set AOC_SESSION or write it to {}",
+                path.display()
+            ),
+            ClientError::Status { status, body } => {
+                write!(f, "the server answered {}: {}", status, body.trim())
+            }
+            ClientError::Transport(message) => write!(f, "{}", message),
+            ClientError::UnexpectedResponse(_) => {
+                write!(
+                    f,
+                    "the server's response doesn't say whether the answer is right"
+                )
+            }
+            ClientError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> ClientError {
+        match error {
+            ureq::Error::Status(status, response) => ClientError::Status {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(error: io::Error) -> ClientError {
+        ClientError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer!  You are one gold star closer."
+            ))
+            .unwrap(),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high."
+            ))
+            .unwrap(),
+            Verdict::Incorrect {
+                hint: Some("too high".into())
+            }
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.")).unwrap(),
+            Verdict::Incorrect { hint: None }
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 37s left to wait."
+            ))
+            .unwrap(),
+            Verdict::RateLimited {
+                wait: Some("37s".into())
+            }
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap(),
+            Verdict::AlreadySolved
+        );
+        assert!(Verdict::parse(&page("Welcome!")).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use std::{io, path::PathBuf};
+
+use aoc::{visualize::Canvas, Answer, Input, ParseError, Part, Solution, Timings, Visualize};
+
+/// A registered day, with its solution type erased so all of them fit in [`DAYS`].
+pub struct Day {
+    pub number: u8,
+    solve: fn(&Input, &[Part]) -> Result<Vec<Answer>, ParseError>,
+    solve_timed: fn(&Input, &[Part]) -> Result<Timings, ParseError>,
+    visualize: Option<Visualization>,
+}
+
+/// How a day that implements [`Visualize`] draws itself.
+#[derive(Clone, Copy)]
+struct Visualization {
+    parts: &'static [Part],
+    visualize: VisualizeFn,
+}
+
+type VisualizeFn = fn(&Input, Part, &mut dyn Canvas) -> Result<io::Result<()>, ParseError>;
+
+impl Day {
+    const fn new<S: Solution>() -> Day {
+        Day {
+            number: S::DAY,
+            solve: aoc::solve::<S>,
+            solve_timed: aoc::solve_timed::<S>,
+            visualize: None,
+        }
+    }
+
+    const fn visualized<S: Visualize>() -> Day {
+        Day {
+            visualize: Some(Visualization {
+                parts: S::PARTS,
+                visualize: aoc::visualize::visualize::<S>,
+            }),
+            ..Day::new::<S>()
+        }
+    }
+
+    /// Looks up a day by its number in the advent calendar.
+    pub fn find(number: u8) -> Option<&'static Day> {
+        DAYS.iter().find(|day| day.number == number)
+    }
+
+    /// Where the puzzle input lives, relative to the workspace root.
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(format!("day{:02}", self.number)).join("input.txt")
+    }
+
+    /// Parses `input` once and returns the answer to each of `parts`, in order.
+    pub fn solve(&self, input: &Input, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Same as [`Day::solve`], also timing parsing and each part.
+    pub fn solve_timed(&self, input: &Input, parts: &[Part]) -> Result<Timings, ParseError> {
+        (self.solve_timed)(input, parts)
+    }
+
+    /// The parts this day can draw being solved, none for most days.
+    pub fn visualized_parts(&self) -> &'static [Part] {
+        self.visualize.map_or(&[], |visualize| visualize.parts)
+    }
+
+    /// Parses `input` and draws `part` being solved on `canvas`. Parse errors
+    /// come out first, errors drawing inside.
+    ///
+    /// Panics if the day can't draw `part`, see [`Day::visualized_parts`].
+    pub fn visualize(
+        &self,
+        input: &Input,
+        part: Part,
+        canvas: &mut dyn Canvas,
+    ) -> Result<io::Result<()>, ParseError> {
+        assert!(
+            self.visualized_parts().contains(&part),
+            "Day {} can't draw part {}",
+            self.number,
+            part
+        );
+        (self.visualize.unwrap().visualize)(input, part, canvas)
+    }
+}
+
+pub static DAYS: [Day; 25] = [
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::visualized::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::visualized::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day21::Day21>(),
+    Day::new::<day22::Day22>(),
+    Day::visualized::<day23::Day23>(),
+    Day::visualized::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::Frame;
+
+    #[test]
+    fn test_days_are_in_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_solve_from_string() {
+        let day = Day::find(1).unwrap();
+        let input = Input::from("1721\n979\n366\n299\n675\n1456\n");
+
+        assert_eq!(
+            day.solve(&input, &Part::ALL),
+            Ok(vec![Answer::from(514579), Answer::from(241861950)])
+        );
+    }
+
+    #[test]
+    fn test_solve_reports_parse_errors() {
+        let day = Day::find(1).unwrap();
+        let input = Input::from(
+            "1721
+97x
+",
+        );
+        let error = day.solve(&input, &Part::ALL).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    /// Keeps every frame it's given.
+    struct Frames(Vec<Frame>);
+
+    impl Canvas for Frames {
+        fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.push(frame.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_visualize() {
+        assert!(Day::find(1).unwrap().visualized_parts().is_empty());
+        assert_eq!(Day::find(11).unwrap().visualized_parts(), &Part::ALL);
+
+        let day = Day::find(23).unwrap();
+        assert_eq!(day.visualized_parts(), &[Part::One]);
+
+        let mut frames = Frames(vec![]);
+        day.visualize(&Input::from("389125467"), Part::One, &mut frames)
+            .unwrap()
+            .unwrap();
+
+        // Before each of the 100 moves, and after the last
+        assert_eq!(frames.0.len(), 101);
+        assert_eq!((frames.0[0].width(), frames.0[0].height()), (17, 9));
+    }
+}
@@ -0,0 +1,11 @@
+//! Every day's solution behind a single registry, so they can be run by day
+//! number instead of through 25 separate binaries.
+
+pub mod alloc;
+pub mod client;
+pub mod days;
+pub mod report;
+
+pub use client::{Client, ClientError, Verdict};
+pub use days::{Day, DAYS};
+pub use report::{PasswordCheck, Record};
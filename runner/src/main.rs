@@ -0,0 +1,845 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    process,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    trace::{self, Tracer},
+    visualize::{Canvas, ImageFormat, Images, Terminal},
+    Answer, Input, InputSource, ParseError, Part, Solution, Timings,
+};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use generate::Generator;
+use rayon::prelude::*;
+use runner::{alloc, Client, Day, PasswordCheck, Record, DAYS};
+
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day, or every day with --all
+    Run(RunArgs),
+    /// Times parsing and each part of every day and prints a summary table
+    Bench(BenchArgs),
+    /// Downloads a day's input into dayNN/input.txt, unless it is already there
+    Fetch(FetchArgs),
+    /// Submits an answer, solving the day to get it unless --answer is given
+    Submit(SubmitArgs),
+    /// Writes a random input for a day, the same one for the same seed and size
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("k_sum").multiple(true)))]
+struct RunArgs {
+    /// Day to solve
+    #[arg(
+        long,
+        value_parser = clap::value_parser!(u8).range(1..=25),
+        required_unless_present = "all"
+    )]
+    day: Option<u8>,
+
+    /// Part to solve, both if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input file, or `-` for stdin. Defaults to dayNN/input.txt
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// The puzzle input itself, e.g. `2,15,0,9,1,20` for day 15
+    #[arg(long, requires = "day", conflicts_with = "input")]
+    input_text: Option<String>,
+
+    /// Solve every day using its default input
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+
+    /// How many days to solve at once with --all, one per CPU by default
+    #[arg(long, requires = "all", value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+
+    /// Draw every step of solving the day instead of printing the answers, for
+    /// the simulations of days 11, 17, 23 and 24
+    #[arg(long, requires = "day", conflicts_with = "format")]
+    visualize: bool,
+
+    /// Milliseconds to show each frame for with --visualize
+    #[arg(long, requires = "visualize", default_value_t = 100)]
+    frame_delay: u64,
+
+    /// Wait for Enter after each frame with --visualize, instead of --frame-delay
+    #[arg(long, requires = "visualize", conflicts_with_all = ["frame_delay", "frames_dir"])]
+    step: bool,
+
+    /// Save the frames as images in this directory with --visualize, instead of
+    /// drawing them in the terminal
+    #[arg(long, requires = "visualize")]
+    frames_dir: Option<PathBuf>,
+
+    /// Image format for --frames-dir
+    #[arg(long, value_enum, requires = "frames_dir", default_value_t = FramesFormat::Ppm)]
+    frames_format: FramesFormat,
+
+    /// Write what the solvers work out on the way to their answers, one event
+    /// per line, to stderr or --trace-file
+    #[arg(long)]
+    trace: bool,
+
+    /// File to write trace events to, instead of stderr
+    #[arg(long, requires = "trace")]
+    trace_file: Option<PathBuf>,
+
+    /// Only trace these days, e.g. `16,21`. Every day solved if omitted
+    #[arg(
+        long,
+        requires = "trace",
+        value_delimiter = ',',
+        value_parser = clap::value_parser!(u8).range(1..=25)
+    )]
+    trace_days: Vec<u8>,
+
+    /// For day 1, print the ways this many entries add up to --target instead
+    /// of the answers. Defaults to 2 with --target
+    #[arg(
+        long,
+        group = "k_sum",
+        requires = "day",
+        conflicts_with_all = ["format", "visualize"]
+    )]
+    k: Option<usize>,
+
+    /// For day 1, what --k entries have to add up to. Defaults to 2020 with --k
+    #[arg(
+        long,
+        group = "k_sum",
+        requires = "day",
+        conflicts_with_all = ["format", "visualize"],
+        allow_negative_numbers = true
+    )]
+    target: Option<i64>,
+
+    /// Print every distinct way entries add up to --target, not just the first
+    #[arg(long, requires = "k_sum")]
+    all_solutions: bool,
+
+    /// For day 1, count the ways --k entries add up to --target and print the
+    /// ones with the largest and smallest product
+    #[arg(
+        long,
+        group = "k_sum",
+        requires = "day",
+        conflicts_with_all = ["format", "visualize"]
+    )]
+    report: bool,
+
+    /// For day 2, count the passwords valid under this policy instead of
+    /// printing the answers: `count`, `positions`, `distinct:<n>`,
+    /// `forbidden:<substring>,...` or `regex:<regex>`. Can be given more than once
+    #[arg(
+        long,
+        requires = "day",
+        conflicts_with_all = ["format", "visualize", "k_sum"]
+    )]
+    policy: Vec<String>,
+
+    /// For day 2, check every password against each --policy, `count` and
+    /// `positions` by default, and print whether it passes and why not
+    #[arg(
+        long,
+        value_enum,
+        requires = "day",
+        conflicts_with_all = ["format", "visualize", "k_sum"]
+    )]
+    audit: Option<AuditFormat>,
+
+    /// For day 3, go down every slope up to --max-right and --max-down and
+    /// print the ones with the fewest and the most trees
+    #[arg(
+        long,
+        requires = "day",
+        conflicts_with_all = ["format", "visualize", "k_sum", "policy", "audit"]
+    )]
+    search_slopes: bool,
+
+    /// How far right the slopes --search-slopes tries go at most. Defaults to
+    /// the width of the map, going further lands on the same squares
+    #[arg(long, requires = "search_slopes")]
+    max_right: Option<usize>,
+
+    /// How far down the slopes --search-slopes tries go at most. Defaults to
+    /// the height of the map
+    #[arg(long, requires = "search_slopes")]
+    max_down: Option<usize>,
+
+    /// For day 3, print the map with the squares the toboggan goes through
+    /// marked, `O` where they're open and `X` where there's a tree
+    #[arg(
+        long,
+        requires = "day",
+        conflicts_with_all = ["format", "visualize", "k_sum", "policy", "audit", "search_slopes"]
+    )]
+    render_path: bool,
+
+    /// How far right the toboggan goes each move with --render-path
+    #[arg(long, requires = "render_path", default_value_t = 3)]
+    right: usize,
+
+    /// How far down the toboggan goes each move with --render-path
+    #[arg(
+        long,
+        requires = "render_path",
+        default_value_t = 1,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    down: usize,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One `Day N part P: answer` line per answer
+    Human,
+    /// One JSON object per answer and line, with `day`, `part`, `answer` and `elapsed_ms`
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum AuditFormat {
+    /// A header line, then one line per password and policy
+    Csv,
+    /// One JSON object per password and policy, and line
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FramesFormat {
+    /// Black and white PBM images
+    Pbm,
+    /// Color PPM images
+    Ppm,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to time, every day if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// How many times to solve each day, the table shows the median
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+}
+
+/// Fetching and submitting need the session token, either in the AOC_SESSION
+/// environment variable or in ~/.config/aoc/session
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download, every day without an input yet if omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Download the input even if it is already there
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part to submit
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The answer to submit, instead of solving the day's input
+    #[arg(long)]
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Seed for the random number generator
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// How big the input is, in what unit depends on the day. Defaults to about
+    /// the size of a real input
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Generate an input with the edge cases the puzzle rules out, for the days
+    /// that have them (10 and 16)
+    #[arg(long)]
+    edge_cases: bool,
+
+    /// File to write the input to, instead of stdout
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Generate(args) => generate(args),
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let parts = match args.part {
+        Some(1) => vec![Part::One],
+        Some(_) => vec![Part::Two],
+        None => Part::ALL.to_vec(),
+    };
+
+    let days: Vec<&'static Day> = match args.day {
+        Some(number) => Day::find(number).into_iter().collect(),
+        None => DAYS.iter().collect(),
+    };
+
+    let source = |day: &Day| match (&args.input, &args.input_text) {
+        (Some(arg), _) => InputSource::from_arg(arg),
+        (_, Some(text)) => InputSource::Text(text.clone()),
+        _ => InputSource::File(day.input_path()),
+    };
+
+    if args.trace {
+        let tracer = match &args.trace_file {
+            Some(path) => Tracer::new(io::BufWriter::new(
+                fs::File::create(path)
+                    .map_err(|error| format!("unable to create {}: {}", path.display(), error))?,
+            )),
+            None => Tracer::new(io::stderr()),
+        };
+        tracer.days(&args.trace_days).install();
+    }
+
+    // The paths that do something else with a day than solving it
+    let day = days[0];
+    let in_context = |f: &dyn Fn() -> Result<(), String>| trace::in_context(day.number, None, f);
+    let side_path = if args.visualize {
+        // Without --part, draw every part the day can
+        let parts = match args.part {
+            Some(_) => parts.clone(),
+            None => day.visualized_parts().to_vec(),
+        };
+        Some(visualize(&args, day, &source(day), &parts))
+    } else if args.k.is_some() || args.target.is_some() || args.report {
+        Some(in_context(&|| k_sum(&args, day, &source(day))))
+    } else if !args.policy.is_empty() || args.audit.is_some() {
+        Some(in_context(&|| check_passwords(&args, day, &source(day))))
+    } else if args.search_slopes {
+        Some(in_context(&|| search_slopes(&args, day, &source(day))))
+    } else if args.render_path {
+        Some(in_context(&|| render_path(&args, day, &source(day))))
+    } else {
+        None
+    };
+    if let Some(result) = side_path {
+        return result
+            .and(trace::finish().map_err(|error| format!("unable to write the trace: {}", error)));
+    }
+
+    let solve = |day: &'static Day| -> Result<DayRun, String> {
+        let source = source(day);
+        let input = read_input(&source)?;
+
+        let (timings, peak_memory) = alloc::measure(|| day.solve_timed(&input, &parts));
+        let timings = timings.map_err(|error| parse_error(&source, &input, error))?;
+
+        Ok(DayRun {
+            day,
+            timings,
+            peak_memory,
+        })
+    };
+
+    let start = Instant::now();
+    let runs = if days.len() > 1 {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(args.jobs.map_or(0, usize::from))
+            .build()
+            .map_err(|error| format!("unable to start the thread pool: {}", error))?
+            .install(|| days.par_iter().map(|day| solve(day)).collect::<Vec<_>>())
+    } else {
+        days.iter().map(|day| solve(day)).collect()
+    };
+    let wall_clock = start.elapsed();
+
+    trace::finish().map_err(|error| format!("unable to write the trace: {}", error))?;
+    let runs = runs.into_iter().collect::<Result<Vec<DayRun>, String>>()?;
+
+    for run in &runs {
+        for (part, answer, elapsed) in &run.timings.parts {
+            if *answer == Answer::None {
+                continue;
+            }
+
+            match args.format {
+                Format::Human => println!("Day {} part {}: {}", run.day.number, part, answer),
+                Format::Json => println!(
+                    "{}",
+                    Record::new(run.day.number, *part, answer.clone(), *elapsed).to_json()
+                ),
+            }
+        }
+    }
+
+    if runs.len() > 1 && matches!(args.format, Format::Human) {
+        print_summary(runs, wall_clock);
+    }
+
+    Ok(())
+}
+
+fn visualize(
+    args: &RunArgs,
+    day: &Day,
+    source: &InputSource,
+    parts: &[Part],
+) -> Result<(), String> {
+    let drawable: Vec<String> = DAYS
+        .iter()
+        .filter(|day| !day.visualized_parts().is_empty())
+        .map(|day| day.number.to_string())
+        .collect();
+
+    if parts.is_empty() {
+        return Err(format!(
+            "day {} has nothing to draw, only days {} do",
+            day.number,
+            drawable.join(", ")
+        ));
+    }
+    if let Some(part) = parts
+        .iter()
+        .find(|part| !day.visualized_parts().contains(part))
+    {
+        return Err(format!("day {} can't draw part {}", day.number, part));
+    }
+    if args.step && *source == InputSource::Stdin {
+        return Err("--step reads Enter from stdin, so the input can't come from it".into());
+    }
+
+    let input = read_input(source)?;
+
+    for part in parts {
+        let draw = |canvas: &mut dyn Canvas| {
+            day.visualize(&input, *part, canvas)
+                .map_err(|error| parse_error(source, &input, error))?
+                .map_err(|error| {
+                    format!("unable to draw day {} part {}: {}", day.number, part, error)
+                })
+        };
+
+        match &args.frames_dir {
+            Some(dir) => {
+                let format = match args.frames_format {
+                    FramesFormat::Pbm => ImageFormat::Pbm,
+                    FramesFormat::Ppm => ImageFormat::Ppm,
+                };
+                let prefix = format!("day{:02}-part{}", day.number, part);
+                let mut images = Images::new(dir, prefix, format)
+                    .map_err(|error| format!("unable to create {}: {}", dir.display(), error))?;
+
+                draw(&mut images)?;
+                println!(
+                    "Saved {} frames of day {} part {} in {}",
+                    images.frames(),
+                    day.number,
+                    part,
+                    dir.display()
+                );
+            }
+            None if args.step => draw(&mut Terminal::stepping(io::stdout()))?,
+            None => draw(&mut Terminal::new(
+                io::stdout(),
+                Duration::from_millis(args.frame_delay),
+            ))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the entries of day 1 adding up to `--target`, one solution a line,
+/// or a summary of them with `--report`.
+fn k_sum(args: &RunArgs, day: &Day, source: &InputSource) -> Result<(), String> {
+    if day.number != 1 {
+        return Err("--k, --target and --report only apply to day 1".into());
+    }
+
+    let input = read_input(source)?;
+    let report = day01::Day01::parse(&input).map_err(|error| parse_error(source, &input, error))?;
+    let (k, target) = (args.k.unwrap_or(2), args.target.unwrap_or(day01::TARGET));
+    let solutions = if args.all_solutions {
+        day01::Solutions::All
+    } else {
+        day01::Solutions::First
+    };
+
+    if args.report {
+        let report = day01::report(report.numbers(), k, target);
+        if args.all_solutions {
+            for entries in &report.solutions {
+                println!("{}", with_product(entries, day01::product(entries)));
+            }
+        }
+        print_report(&report, k, target);
+        return Ok(());
+    }
+
+    let found = day01::k_sum(report.numbers(), k, target, solutions);
+    if found.is_empty() {
+        return Err(format!("no {} entries add up to {}", k, target));
+    }
+
+    for entries in found {
+        println!("{} = {}", day01::sum(&entries), target);
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &day01::Report, k: usize, target: i64) {
+    println!(
+        "{} ways for {} entries to add up to {}",
+        report.solutions.len(),
+        k,
+        target
+    );
+
+    if let (Some((largest, most)), Some((smallest, least))) = (&report.largest, &report.smallest) {
+        println!("Largest product: {}", with_product(largest, Some(*most)));
+        println!("Smallest product: {}", with_product(smallest, Some(*least)));
+    }
+    if report.overflowing > 0 {
+        println!(
+            "{} of them have a product too large for a 64-bit integer",
+            report.overflowing
+        );
+    }
+}
+
+/// Entries as a product, e.g. `1721 * 299 = 514579`.
+fn with_product(entries: &[i64], product: Option<i64>) -> String {
+    let factors: Vec<String> = entries.iter().map(|entry| entry.to_string()).collect();
+    match product {
+        Some(product) => format!("{} = {}", factors.join(" * "), product),
+        None => format!("{} overflows", factors.join(" * ")),
+    }
+}
+
+/// Prints how many passwords of day 2 are valid under each `--policy`, or
+/// how every password fares under them with `--audit`.
+fn check_passwords(args: &RunArgs, day: &Day, source: &InputSource) -> Result<(), String> {
+    if day.number != 2 {
+        return Err("--policy and --audit only apply to day 2".into());
+    }
+
+    let names = match args.policy.as_slice() {
+        [] => vec!["count".to_string(), "positions".to_string()],
+        names => names.to_vec(),
+    };
+    let policies = names
+        .iter()
+        .map(|name| day02::policy(name).map_err(|error| format!("invalid policy: {}", error)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let input = read_input(source)?;
+    let database =
+        day02::Day02::parse(&input).map_err(|error| parse_error(source, &input, error))?;
+
+    if let Some(format) = args.audit {
+        let checks = database.check_all(&policies);
+
+        match format {
+            AuditFormat::Csv => {
+                println!("{}", PasswordCheck::CSV_HEADER);
+                for check in &checks {
+                    println!("{}", PasswordCheck::new(check).to_csv());
+                }
+            }
+            AuditFormat::Json => {
+                for check in &checks {
+                    println!("{}", PasswordCheck::new(check).to_json());
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    for policy in policies {
+        println!(
+            "Day 2 under {}: {} of {} passwords are valid",
+            policy,
+            database.count_valid(policy.as_ref()),
+            database.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints the slopes of day 3 with the fewest and the most trees.
+fn search_slopes(args: &RunArgs, day: &Day, source: &InputSource) -> Result<(), String> {
+    if day.number != 3 {
+        return Err("--search-slopes only applies to day 3".into());
+    }
+
+    let input = read_input(source)?;
+    let day03 = day03::Day03::parse(&input).map_err(|error| parse_error(source, &input, error))?;
+    let map = day03.map();
+    let rights = 1..=args.max_right.unwrap_or(map.width());
+    let downs = 1..=args.max_down.unwrap_or(map.height());
+
+    let (fewest, most) = map
+        .search_slopes(rights, downs)
+        .ok_or("no slopes to search, --max-right and --max-down have to be at least 1")?;
+
+    // Plenty of steep slopes leave the map before hitting anything, no need
+    // to list them all
+    const LISTED: usize = 10;
+
+    for (name, extreme) in [("Fewest", fewest), ("Most", most)] {
+        let mut slopes: Vec<String> = extreme
+            .slopes
+            .iter()
+            .take(LISTED)
+            .map(ToString::to_string)
+            .collect();
+        if extreme.slopes.len() > LISTED {
+            slopes.push(format!("and {} more", extreme.slopes.len() - LISTED));
+        }
+
+        println!(
+            "{} trees: {}, going {}",
+            name,
+            extreme.trees,
+            slopes.join("; ")
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints the map of day 3 with the path down `--right` and `--down` on it.
+fn render_path(args: &RunArgs, day: &Day, source: &InputSource) -> Result<(), String> {
+    if day.number != 3 {
+        return Err("--render-path only applies to day 3".into());
+    }
+
+    let input = read_input(source)?;
+    let day03 = day03::Day03::parse(&input).map_err(|error| parse_error(source, &input, error))?;
+    let slope = day03::Slope::new(args.right, args.down);
+    let path = day03.map().count_trees(slope);
+
+    print!("{}", day03.map().render(&path));
+    println!("Going {}: {} trees", slope, path.trees);
+
+    Ok(())
+}
+
+/// A day solved by `aoc run`, with what it took.
+struct DayRun {
+    day: &'static Day,
+    timings: Timings,
+    peak_memory: usize,
+}
+
+/// Lists the days from slowest to fastest, to tell where the time goes.
+fn print_summary(mut runs: Vec<DayRun>, wall_clock: Duration) {
+    runs.sort_by_key(|run| std::cmp::Reverse(run.timings.total()));
+
+    println!();
+    println!("{:>3}  {:>12}  {:>12}", "Day", "Time", "Peak memory");
+    for run in &runs {
+        println!(
+            "{:>3}  {:>12}  {:>12}",
+            run.day.number,
+            format_duration(run.timings.total()),
+            format_bytes(run.peak_memory)
+        );
+    }
+
+    let total: Duration = runs.iter().map(|run| run.timings.total()).sum();
+    println!(
+        "Solved {} days in {} ({} summed over days)",
+        runs.len(),
+        format_duration(wall_clock),
+        format_duration(total)
+    );
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => Day::find(number).into_iter().collect(),
+        None => DAYS.iter().collect(),
+    };
+
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+
+    let mut total = Duration::default();
+
+    for day in days {
+        let source = InputSource::File(day.input_path());
+        let input = read_input(&source)?;
+
+        let runs = (0..args.runs)
+            .map(|_| day.solve_timed(&input, &Part::ALL))
+            .collect::<Result<Vec<Timings>, _>>()
+            .map_err(|error| parse_error(&source, &input, error))?;
+
+        let median = |stage: &dyn Fn(&Timings) -> Duration| {
+            let mut durations: Vec<Duration> = runs.iter().map(stage).collect();
+            durations.sort();
+            durations[durations.len() / 2]
+        };
+        let part = |i: usize| median(&|timings| timings.parts[i].2);
+        let day_total = median(&Timings::total);
+        total += day_total;
+
+        println!(
+            "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+            day.number,
+            format_duration(median(&|timings| timings.parse)),
+            format_duration(part(0)),
+            format_duration(part(1)),
+            format_duration(day_total)
+        );
+    }
+
+    println!("{:>3}  {:>56}", "", format_duration(total));
+
+    Ok(())
+}
+
+fn fetch(args: FetchArgs) -> Result<(), String> {
+    let client = Client::from_env().map_err(|error| error.to_string())?;
+
+    let days: Vec<&Day> = match args.day {
+        Some(number) => Day::find(number).into_iter().collect(),
+        None => DAYS.iter().collect(),
+    };
+
+    for day in days {
+        let path = day.input_path();
+        if path.is_file() && !args.force {
+            continue;
+        }
+
+        client
+            .cached_input(day.number, &path, args.force)
+            .map_err(|error| format!("unable to download day {}: {}", day.number, error))?;
+        println!("Downloaded {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), String> {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+
+    let answer = match args.answer {
+        Some(answer) => Answer::Text(answer),
+        None => {
+            let day = Day::find(args.day).expect("Every day is registered");
+            let source = InputSource::File(day.input_path());
+            let input = read_input(&source)?;
+
+            day.solve(&input, &[part])
+                .map_err(|error| parse_error(&source, &input, error))?
+                .remove(0)
+        }
+    };
+
+    if answer == Answer::None {
+        return Err(format!("day {} has no part {} to submit", args.day, part));
+    }
+
+    let client = Client::from_env().map_err(|error| error.to_string())?;
+    let verdict = client
+        .submit(args.day, part, &answer)
+        .map_err(|error| format!("unable to submit: {}", error))?;
+
+    println!("Day {} part {}: {} is {}", args.day, part, answer, verdict);
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), String> {
+    let generator = Generator::find(args.day).expect("Every day has a generator");
+    let size = args.size.unwrap_or(generator.default_size);
+    let input = if args.edge_cases {
+        generator
+            .generate_edge_cases(args.seed, size)
+            .ok_or_else(|| format!("day {} has no edge cases to generate", args.day))?
+    } else {
+        generator.generate(args.seed, size)
+    };
+
+    match args.output {
+        Some(path) => fs::write(&path, input)
+            .map_err(|error| format!("unable to write {}: {}", path.display(), error)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.1} KiB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MiB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+fn read_input(source: &InputSource) -> Result<Input, String> {
+    source
+        .read()
+        .map_err(|error| format!("unable to read {}: {}", source, error))
+}
+
+fn parse_error(source: &InputSource, input: &Input, error: ParseError) -> String {
+    format!("unable to parse {}: {}", source, error.diagnostic(input))
+}
@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use aoc::{Answer, Part};
+
+/// One answer in the machine-readable output of `aoc run --format json`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    #[serde(serialize_with = "serialize_answer")]
+    pub answer: Answer,
+    pub elapsed_ms: f64,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, answer: Answer, elapsed: Duration) -> Record {
+        Record {
+            day,
+            part: part.number(),
+            answer,
+            elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+        }
+    }
+
+    /// The record as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Records always serialize")
+    }
+}
+
+/// One password of day 2 checked against one policy, in the output of
+/// `aoc run --day 2 --audit`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PasswordCheck {
+    pub line: usize,
+    pub rule: String,
+    pub password: String,
+    pub policy: String,
+    pub valid: bool,
+    /// Why the password isn't valid, null if it is
+    pub reason: Option<String>,
+}
+
+impl PasswordCheck {
+    pub const CSV_HEADER: &'static str = "line,rule,password,policy,valid,reason";
+
+    pub fn new(check: &day02::Check) -> PasswordCheck {
+        PasswordCheck {
+            line: check.line,
+            rule: check.rule.to_string(),
+            password: check.password.to_string(),
+            policy: check.policy.to_string(),
+            valid: check.violation.is_none(),
+            reason: check.violation.clone(),
+        }
+    }
+
+    /// The check as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Password checks always serialize")
+    }
+
+    /// The check as a line of CSV, with the columns of [`PasswordCheck::CSV_HEADER`].
+    pub fn to_csv(&self) -> String {
+        [
+            self.line.to_string(),
+            csv_field(&self.rule),
+            csv_field(&self.password),
+            csv_field(&self.policy),
+            self.valid.to_string(),
+            csv_field(self.reason.as_deref().unwrap_or("")),
+        ]
+        .join(",")
+    }
+}
+
+/// Quotes a field if it has anything CSV readers would split it on, doubling
+/// the quotes inside.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Numbers stay JSON numbers and text stays a string, without the quoting
+/// `Debug` would add. Missing answers are null.
+fn serialize_answer<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Number(n) => serializer.serialize_i64(*n),
+        Answer::Text(text) => serializer.serialize_str(text),
+        Answer::None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_json() {
+        let record = Record::new(
+            1,
+            Part::Two,
+            Answer::from(241861950),
+            Duration::from_micros(1500),
+        );
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":1,"part":2,"answer":241861950,"elapsed_ms":1.5}"#
+        );
+
+        let record = Record::new(21, Part::Two, Answer::from("mxmxvkd,sqjhc"), Duration::ZERO);
+        assert_eq!(
+            record.to_json(),
+            r#"{"day":21,"part":2,"answer":"mxmxvkd,sqjhc","elapsed_ms":0.0}"#
+        );
+    }
+
+    #[test]
+    fn test_password_check() {
+        let check = PasswordCheck {
+            line: 2,
+            rule: "1-3 b".into(),
+            password: "cd,\"fg".into(),
+            policy: "regex:^[a-z]{2,}$".into(),
+            valid: false,
+            reason: Some("doesn't match `^[a-z]{2,}$`".into()),
+        };
+        assert_eq!(
+            check.to_csv(),
+            r#"2,1-3 b,"cd,""fg","regex:^[a-z]{2,}$",false,"doesn't match `^[a-z]{2,}$`""#
+        );
+        assert_eq!(
+            check.to_json(),
+            r#"{"line":2,"rule":"1-3 b","password":"cd,\"fg","policy":"regex:^[a-z]{2,}$","valid":false,"reason":"doesn't match `^[a-z]{2,}$`"}"#
+        );
+
+        let check = PasswordCheck {
+            valid: true,
+            reason: None,
+            ..check
+        };
+        assert!(check.to_csv().ends_with(",true,"));
+        assert!(check.to_json().ends_with(r#""valid":true,"reason":null}"#));
+    }
+}
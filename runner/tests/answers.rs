@@ -0,0 +1,138 @@
+//! Runs every day against the answers registered in `answers.txt` at the root of
+//! the repository, so a change that breaks any of them fails `cargo test`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc::{Input, Part};
+use runner::Day;
+
+struct ExpectedAnswer {
+    day: u8,
+    part: Part,
+    input: PathBuf,
+    answer: String,
+}
+
+fn root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+fn expected_answers() -> Vec<ExpectedAnswer> {
+    let registry =
+        fs::read_to_string(root().join("answers.txt")).expect("Unable to read answers.txt");
+
+    registry
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (day, part, input, answer) = match fields.as_slice() {
+                [day, part, input, answer] => (day, part, input, answer),
+                _ => panic!("answers.txt:{}: expected `day part input answer`", i + 1),
+            };
+
+            ExpectedAnswer {
+                day: day
+                    .parse()
+                    .unwrap_or_else(|_| panic!("answers.txt:{}: invalid day {}", i + 1, day)),
+                part: match *part {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    other => panic!("answers.txt:{}: invalid part {}", i + 1, other),
+                },
+                input: PathBuf::from(input),
+                answer: answer.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn check_day(number: u8) {
+    let day = Day::find(number).unwrap();
+    let expected: Vec<ExpectedAnswer> = expected_answers()
+        .into_iter()
+        .filter(|expected| expected.day == number)
+        .collect();
+
+    assert!(
+        !expected.is_empty(),
+        "Day {} has no answers in answers.txt",
+        number
+    );
+
+    let mut failures = vec![];
+
+    for expected in &expected {
+        let input = Input::from_file(root().join(&expected.input)).unwrap_or_else(|error| {
+            panic!("Unable to read {}: {}", expected.input.display(), error)
+        });
+
+        match day.solve(&input, &[expected.part]) {
+            Ok(answers) if answers[0].to_string() == expected.answer => {}
+            Ok(answers) => failures.push(format!(
+                "part {} on {}: expected {}, got {}",
+                expected.part,
+                expected.input.display(),
+                expected.answer,
+                answers[0]
+            )),
+            Err(error) => failures.push(format!(
+                "part {} on {}: {}",
+                expected.part,
+                expected.input.display(),
+                error
+            )),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Day {} answers changed:\n{}",
+        number,
+        failures.join("\n")
+    );
+}
+
+// One test per day so they run in parallel and failures point at the day
+macro_rules! answer_tests {
+    ($($name:ident: $day:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    day01: 1,
+    day02: 2,
+    day03: 3,
+    day04: 4,
+    day05: 5,
+    day06: 6,
+    day07: 7,
+    day08: 8,
+    day09: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+    day20: 20,
+    day21: 21,
+    day22: 22,
+    day23: 23,
+    day24: 24,
+    day25: 25,
+}
@@ -0,0 +1,150 @@
+//! Runs the client against a local stand-in for the Advent of Code website, so
+//! none of these tests touch the network.
+
+use std::{
+    fs,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use aoc::{Answer, Part};
+use runner::{Client, ClientError, Verdict};
+use tiny_http::{Response, Server};
+
+const SESSION: &str = "53616c7465645f5f";
+
+/// A request as the stand-in server saw it.
+#[derive(Debug, Clone)]
+struct Received {
+    method: String,
+    url: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+/// Serves `responses` in order, one per request, then shuts down. Returns the
+/// server's base URL and the requests it received.
+fn serve(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<Received>>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&received);
+
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let mut request = server.recv().unwrap();
+
+            let mut content = String::new();
+            request.as_reader().read_to_string(&mut content).unwrap();
+            log.lock().unwrap().push(Received {
+                method: request.method().to_string(),
+                url: request.url().into(),
+                cookie: request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string()),
+                body: content,
+            });
+
+            request
+                .respond(Response::from_string(body).with_status_code(status))
+                .unwrap();
+        }
+    });
+
+    (base_url, received)
+}
+
+#[test]
+fn test_input_sends_session() {
+    let (base_url, received) = serve(vec![(200, "1721\n979\n")]);
+    let client = Client::with_base_url(base_url, SESSION);
+
+    assert_eq!(client.input(1).unwrap(), "1721\n979\n");
+
+    let received = received.lock().unwrap();
+    assert_eq!(received[0].method, "GET");
+    assert_eq!(received[0].url, "/2020/day/1/input");
+    assert_eq!(
+        received[0].cookie.as_deref(),
+        Some("session=53616c7465645f5f")
+    );
+}
+
+#[test]
+fn test_input_error_status() {
+    let (base_url, _) = serve(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    )]);
+    let client = Client::with_base_url(base_url, SESSION);
+
+    match client.input(1) {
+        Err(ClientError::Status { status, body }) => {
+            assert_eq!(status, 400);
+            assert!(body.starts_with("Puzzle inputs differ by user."));
+        }
+        result => panic!("expected a 400, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_cached_input_downloads_once() {
+    let (base_url, received) = serve(vec![(200, "0,3,6\n"), (200, "1,3,2\n")]);
+    let client = Client::with_base_url(base_url, SESSION);
+
+    let dir = std::env::temp_dir().join(format!("aoc-client-test-{}", std::process::id()));
+    let path = dir.join("day15").join("input.txt");
+    let _ = fs::remove_dir_all(&dir);
+
+    assert_eq!(client.cached_input(15, &path, false).unwrap(), "0,3,6\n");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "0,3,6\n");
+    assert_eq!(client.cached_input(15, &path, false).unwrap(), "0,3,6\n");
+    assert_eq!(received.lock().unwrap().len(), 1);
+
+    assert_eq!(client.cached_input(15, &path, true).unwrap(), "1,3,2\n");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1,3,2\n");
+    assert_eq!(received.lock().unwrap().len(), 2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_submit() {
+    let (base_url, received) = serve(vec![
+        (200, "<article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article>"),
+        (200, "<article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again.</p></article>"),
+        (200, "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 2s left to wait.</p></article>"),
+    ]);
+    let client = Client::with_base_url(base_url, SESSION);
+
+    assert_eq!(
+        client
+            .submit(21, Part::Two, &Answer::from("mxmxvkd,sqjhc,fvjkl"))
+            .unwrap(),
+        Verdict::Correct
+    );
+    assert_eq!(
+        client.submit(1, Part::One, &Answer::from(42)).unwrap(),
+        Verdict::Incorrect {
+            hint: Some("too low".into())
+        }
+    );
+    assert_eq!(
+        client.submit(1, Part::One, &Answer::from(43)).unwrap(),
+        Verdict::RateLimited {
+            wait: Some("1m 2s".into())
+        }
+    );
+
+    let received = received.lock().unwrap();
+    assert_eq!(received[0].method, "POST");
+    assert_eq!(received[0].url, "/2020/day/21/answer");
+    assert_eq!(
+        received[0].cookie.as_deref(),
+        Some("session=53616c7465645f5f")
+    );
+    assert_eq!(received[0].body, "level=2&answer=mxmxvkd%2Csqjhc%2Cfvjkl");
+    assert_eq!(received[1].body, "level=1&answer=42");
+}
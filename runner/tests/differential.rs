@@ -0,0 +1,111 @@
+//! Compares every day's solution with the slow but obviously correct one from
+//! `reference`, on small generated inputs. A failure gives the seed and size,
+//! `aoc generate --day <day> --seed <seed> --size <size>` writes that input,
+//! with `--edge-cases` for the failures from `edge_cases`.
+
+mod reference;
+
+use aoc::{Answer, Input, Part};
+use generate::Generator;
+use proptest::prelude::*;
+use runner::Day;
+
+type Reference = fn(&str) -> Answer;
+
+fn generated(generator: &Generator, seed: u64, size: usize) -> String {
+    generator.generate(seed, size)
+}
+
+fn edge_cases(generator: &Generator, seed: u64, size: usize) -> String {
+    generator.generate_edge_cases(seed, size).unwrap()
+}
+
+/// Solves the input `generate` makes from `seed` and `size` both ways, for the
+/// parts there are references for.
+fn compare(
+    day: u8,
+    generate: fn(&Generator, u64, usize) -> String,
+    seed: u64,
+    size: usize,
+    references: &[(Part, Reference)],
+) -> Result<(), TestCaseError> {
+    let text = generate(Generator::find(day).unwrap(), seed, size);
+    let input = Input::from(text.as_str());
+    let parts: Vec<Part> = references.iter().map(|(part, _)| *part).collect();
+
+    let answers = Day::find(day)
+        .unwrap()
+        .solve(&input, &parts)
+        .map_err(|error| TestCaseError::fail(error.diagnostic(&input)))?;
+
+    for ((part, reference), answer) in references.iter().zip(answers) {
+        prop_assert_eq!(answer, reference(&text), "part {}", part);
+    }
+
+    Ok(())
+}
+
+macro_rules! differential {
+    ($generate:ident; $($day:ident($number:expr) in $sizes:expr => [$($part:ident: $reference:ident),*];)*) => {
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(16))]
+
+            $(
+                #[test]
+                fn $day(seed: u64, size in $sizes) {
+                    compare(
+                        $number,
+                        $generate,
+                        seed,
+                        size,
+                        &[$((Part::$part, reference::$day::$reference as Reference)),*],
+                    )?;
+                }
+            )*
+        }
+    };
+}
+
+differential! {
+    generated;
+    day01(1) in 5..40usize => [One: part1, Two: part2];
+    day02(2) in 0..40usize => [One: part1, Two: part2];
+    day03(3) in 1..40usize => [One: part1, Two: part2];
+    day04(4) in 0..40usize => [One: part1, Two: part2];
+    day05(5) in 2..100usize => [One: part1, Two: part2];
+    day06(6) in 0..40usize => [One: part1, Two: part2];
+    day07(7) in 8..60usize => [One: part1, Two: part2];
+    day08(8) in 4..60usize => [One: part1, Two: part2];
+    day09(9) in 30..80usize => [One: part1, Two: part2];
+    // Part 2 goes through every subset of the adapters
+    day10(10) in 1..15usize => [One: part1, Two: part2];
+    day11(11) in 1..12usize => [One: part1, Two: part2];
+    day12(12) in 0..40usize => [One: part1, Two: part2];
+    // Up to four buses, so trying every minute doesn't take forever
+    day13(13) in 2..35usize => [One: part1, Two: part2];
+    day14(14) in 1..40usize => [One: part1, Two: part2];
+    day15(15) in 1..10usize => [One: part1];
+    // Part 2 goes through the possible orders of the fields
+    day16(16) in 1..7usize => [One: part1, Two: part2];
+    day17(17) in 1..5usize => [One: part1, Two: part2];
+    day18(18) in 0..40usize => [One: part1, Two: part2];
+    day19(19) in 0..40usize => [One: part1, Two: part2];
+    day20(20) in 3..5usize => [One: part1, Two: part2];
+    day21(21) in 2..20usize => [One: part1, Two: part2];
+    day22(22) in 2..14usize => [One: part1, Two: part2];
+    // The game needs more cups than it picks up
+    day23(23) in 5..10usize => [One: part1];
+    day24(24) in 0..30usize => [One: part1, Two: part2];
+    day25(25) in 2..10000usize => [One: part1];
+}
+
+/// The days whose generators have edge cases, on those.
+mod edge_cases {
+    use super::*;
+
+    differential! {
+        edge_cases;
+        day10(10) in 1..15usize => [One: part1, Two: part2];
+        day16(16) in 2..7usize => [One: part1, Two: part2];
+    }
+}
@@ -0,0 +1,58 @@
+//! Every day's generated inputs have to parse, whatever the seed and size.
+
+use aoc::{Input, Part};
+use generate::{Generator, GENERATORS};
+use runner::Day;
+
+fn check_parses(generator: &Generator, seed: u64, size: usize) {
+    let input = Input::from(generator.generate(seed, size));
+    let day = Day::find(generator.day).unwrap();
+
+    if let Err(error) = day.solve(&input, &[]) {
+        panic!(
+            "day {} with seed {} and size {}: {}",
+            generator.day,
+            seed,
+            size,
+            error.diagnostic(&input)
+        );
+    }
+}
+
+#[test]
+fn test_generated_inputs_parse() {
+    for generator in &GENERATORS {
+        for seed in 0..5 {
+            check_parses(generator, seed, generator.default_size);
+        }
+    }
+}
+
+#[test]
+fn test_small_generated_inputs_parse() {
+    for generator in &GENERATORS {
+        for size in 0..5 {
+            check_parses(generator, 1, size);
+        }
+    }
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    // Days whose part 2 takes long no matter the input
+    const SLOW: [u8; 2] = [15, 23];
+
+    for generator in &GENERATORS {
+        let parts: &[Part] = if SLOW.contains(&generator.day) {
+            &[Part::One]
+        } else {
+            &Part::ALL
+        };
+        let input = Input::from(generator.generate(3, generator.default_size));
+
+        Day::find(generator.day)
+            .unwrap()
+            .solve(&input, parts)
+            .unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+//! Every pair and every triple of entries.
+
+use aoc::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let entries = super::numbers(input);
+
+    for (i, a) in entries.iter().enumerate() {
+        for b in &entries[i + 1..] {
+            if a + b == 2020 {
+                return (a * b).into();
+            }
+        }
+    }
+
+    Answer::None
+}
+
+pub fn part2(input: &str) -> Answer {
+    let entries = super::numbers(input);
+
+    for (i, a) in entries.iter().enumerate() {
+        for (j, b) in entries.iter().enumerate().skip(i + 1) {
+            for c in &entries[j + 1..] {
+                if a + b + c == 2020 {
+                    return (a * b * c).into();
+                }
+            }
+        }
+    }
+
+    Answer::None
+}
@@ -0,0 +1,55 @@
+//! Policies read with `split`, letters counted one by one.
+
+use aoc::Answer;
+
+struct Entry {
+    low: usize,
+    high: usize,
+    letter: char,
+    password: Vec<char>,
+}
+
+fn entries(input: &str) -> Vec<Entry> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (policy, password) = line.split_once(": ").unwrap();
+            let (range, letter) = policy.split_once(' ').unwrap();
+            let (low, high) = range.split_once('-').unwrap();
+
+            Entry {
+                low: low.parse().unwrap(),
+                high: high.parse().unwrap(),
+                letter: letter.chars().next().unwrap(),
+                password: password.trim().chars().collect(),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Answer {
+    entries(input)
+        .iter()
+        .filter(|entry| {
+            let count = entry
+                .password
+                .iter()
+                .filter(|letter| **letter == entry.letter)
+                .count();
+            entry.low <= count && count <= entry.high
+        })
+        .count()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    entries(input)
+        .iter()
+        .filter(|entry| {
+            let at = |position: usize| entry.password.get(position - 1) == Some(&entry.letter);
+            at(entry.low) != at(entry.high)
+        })
+        .count()
+        .into()
+}
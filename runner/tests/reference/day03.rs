@@ -0,0 +1,35 @@
+//! The toboggan walked down the map one step at a time. It starts on the top
+//! left square and only counts the trees it moves onto.
+
+use aoc::Answer;
+
+fn trees(input: &str, right: usize, down: usize) -> usize {
+    let rows: Vec<Vec<char>> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim().chars().collect())
+        .collect();
+
+    let (mut row, mut column, mut trees) = (down, right, 0);
+    while row < rows.len() {
+        if rows[row][column % rows[row].len()] == '#' {
+            trees += 1;
+        }
+        row += down;
+        column += right;
+    }
+
+    trees
+}
+
+pub fn part1(input: &str) -> Answer {
+    trees(input, 3, 1).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .map(|(right, down)| trees(input, *right, *down))
+        .product::<usize>()
+        .into()
+}
@@ -0,0 +1,74 @@
+//! Every rule of the puzzle checked as it's written.
+
+use std::collections::HashMap;
+
+use aoc::Answer;
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn passports(input: &str) -> Vec<HashMap<&str, &str>> {
+    super::blocks(input)
+        .iter()
+        .map(|lines| {
+            lines
+                .iter()
+                .flat_map(|line| line.split_whitespace())
+                .map(|field| field.split_once(':').unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+fn has_required(passport: &HashMap<&str, &str>) -> bool {
+    REQUIRED.iter().all(|field| passport.contains_key(field))
+}
+
+fn year_between(value: &str, low: u32, high: u32) -> bool {
+    value.len() == 4
+        && value.chars().all(|c| c.is_ascii_digit())
+        && (low..=high).contains(&value.parse().unwrap())
+}
+
+fn is_valid(field: &str, value: &str) -> bool {
+    match field {
+        "byr" => year_between(value, 1920, 2002),
+        "iyr" => year_between(value, 2010, 2020),
+        "eyr" => year_between(value, 2020, 2030),
+        "hgt" => {
+            let (number, unit) = value.split_at(value.len().saturating_sub(2));
+            match (number.parse::<u32>(), unit) {
+                (Ok(cm), "cm") => (150..=193).contains(&cm),
+                (Ok(inches), "in") => (59..=76).contains(&inches),
+                _ => false,
+            }
+        }
+        "hcl" => {
+            value.len() == 7
+                && value.starts_with('#')
+                && value[1..]
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+        }
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+        "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    passports(input)
+        .iter()
+        .filter(|passport| has_required(passport))
+        .count()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    passports(input)
+        .iter()
+        .filter(|passport| {
+            has_required(passport) && passport.iter().all(|(field, value)| is_valid(field, value))
+        })
+        .count()
+        .into()
+}
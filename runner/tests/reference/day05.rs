@@ -0,0 +1,27 @@
+//! Seat ids read as binary numbers, and every possible id tried for yours.
+
+use aoc::Answer;
+
+fn seat_ids(input: &str) -> Vec<i64> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.trim()
+                .chars()
+                .fold(0, |id, c| 2 * id + if c == 'B' || c == 'R' { 1 } else { 0 })
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Answer {
+    seat_ids(input).into_iter().max().unwrap().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let ids = seat_ids(input);
+
+    (1..1023)
+        .find(|id| !ids.contains(id) && ids.contains(&(id - 1)) && ids.contains(&(id + 1)))
+        .map_or(Answer::None, Answer::from)
+}
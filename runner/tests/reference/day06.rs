@@ -0,0 +1,29 @@
+//! Every question asked of every person in a group.
+
+use aoc::Answer;
+
+fn count(input: &str, everyone: bool) -> usize {
+    super::blocks(input)
+        .iter()
+        .map(|people| {
+            ('a'..='z')
+                .filter(|question| {
+                    let answered = |person: &&str| person.contains(*question);
+                    if everyone {
+                        people.iter().all(answered)
+                    } else {
+                        people.iter().any(answered)
+                    }
+                })
+                .count()
+        })
+        .sum()
+}
+
+pub fn part1(input: &str) -> Answer {
+    count(input, false).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    count(input, true).into()
+}
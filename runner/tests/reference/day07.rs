@@ -0,0 +1,56 @@
+//! Bags opened recursively, without remembering anything about bags already
+//! opened.
+
+use std::collections::HashMap;
+
+use aoc::Answer;
+
+type Rules = HashMap<String, Vec<(usize, String)>>;
+
+fn rules(input: &str) -> Rules {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (bag, contents) = line.trim().split_once(" bags contain ").unwrap();
+            let contents = contents
+                .trim_end_matches('.')
+                .split(", ")
+                .filter(|content| *content != "no other bags")
+                .map(|content| {
+                    let words: Vec<&str> = content.split(' ').collect();
+                    (words[0].parse().unwrap(), words[1..3].join(" "))
+                })
+                .collect();
+
+            (bag.to_string(), contents)
+        })
+        .collect()
+}
+
+fn holds(rules: &Rules, bag: &str, wanted: &str) -> bool {
+    rules[bag]
+        .iter()
+        .any(|(_, inner)| inner == wanted || holds(rules, inner, wanted))
+}
+
+fn bags_inside(rules: &Rules, bag: &str) -> usize {
+    rules[bag]
+        .iter()
+        .map(|(count, inner)| count * (1 + bags_inside(rules, inner)))
+        .sum()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let rules = rules(input);
+
+    rules
+        .keys()
+        .filter(|bag| holds(&rules, bag, "shiny gold"))
+        .count()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    bags_inside(&rules(input), "shiny gold").into()
+}
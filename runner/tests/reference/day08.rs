@@ -0,0 +1,62 @@
+//! The boot code run once per possible fix.
+
+use aoc::Answer;
+
+fn code(input: &str) -> Vec<(String, i64)> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (operation, argument) = line.trim().split_once(' ').unwrap();
+            (operation.to_string(), argument.parse().unwrap())
+        })
+        .collect()
+}
+
+/// The accumulator when the code ends or is about to repeat an instruction,
+/// and whether it ended.
+fn run(code: &[(String, i64)]) -> (i64, bool) {
+    let mut visited = vec![false; code.len()];
+    let (mut accumulator, mut i) = (0, 0i64);
+
+    loop {
+        if i == code.len() as i64 {
+            return (accumulator, true);
+        }
+        if i < 0 || i > code.len() as i64 || visited[i as usize] {
+            return (accumulator, false);
+        }
+        visited[i as usize] = true;
+
+        let (operation, argument) = &code[i as usize];
+        match operation.as_str() {
+            "acc" => accumulator += argument,
+            "jmp" => i += argument - 1,
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+pub fn part1(input: &str) -> Answer {
+    run(&code(input)).0.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let code = code(input);
+
+    for i in 0..code.len() {
+        let mut fixed = code.clone();
+        fixed[i].0 = match fixed[i].0.as_str() {
+            "jmp" => "nop".into(),
+            "nop" => "jmp".into(),
+            _ => continue,
+        };
+
+        if let (accumulator, true) = run(&fixed) {
+            return accumulator.into();
+        }
+    }
+
+    Answer::None
+}
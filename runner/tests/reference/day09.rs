@@ -0,0 +1,39 @@
+//! Every pair in the preamble and every contiguous run of numbers.
+
+use aoc::Answer;
+
+const PREAMBLE: usize = 25;
+
+fn first_invalid(numbers: &[i64]) -> Option<i64> {
+    (PREAMBLE..numbers.len())
+        .find(|i| {
+            let window = &numbers[i - PREAMBLE..*i];
+            !window
+                .iter()
+                .any(|a| window.iter().any(|b| a != b && a + b == numbers[*i]))
+        })
+        .map(|i| numbers[i])
+}
+
+pub fn part1(input: &str) -> Answer {
+    first_invalid(&super::numbers(input)).map_or(Answer::None, Answer::from)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let numbers = super::numbers(input);
+    let invalid = match first_invalid(&numbers) {
+        Some(invalid) => invalid,
+        None => return Answer::None,
+    };
+
+    for start in 0..numbers.len() {
+        for end in start + 2..=numbers.len() {
+            let run = &numbers[start..end];
+            if run.iter().sum::<i64>() == invalid {
+                return (run.iter().min().unwrap() + run.iter().max().unwrap()).into();
+            }
+        }
+    }
+
+    Answer::None
+}
@@ -0,0 +1,54 @@
+//! Every subset of the adapters tried as a chain.
+
+use aoc::Answer;
+
+fn joltages(input: &str) -> Vec<i64> {
+    let mut joltages = super::numbers(input);
+    joltages.sort_unstable();
+    joltages
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut chain = vec![0];
+    chain.extend(joltages(input));
+    chain.push(chain.last().unwrap() + 3);
+
+    if chain
+        .windows(2)
+        .any(|pair| !(1..=3).contains(&(pair[1] - pair[0])))
+    {
+        return Answer::None;
+    }
+
+    let differences = |difference: i64| {
+        chain
+            .windows(2)
+            .filter(|pair| pair[1] - pair[0] == difference)
+            .count()
+    };
+    (differences(1) * differences(3)).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let joltages = joltages(input);
+    let device = joltages.last().unwrap() + 3;
+
+    (0..1u64 << joltages.len())
+        .filter(|subset| {
+            let mut chain = vec![0];
+            chain.extend(
+                joltages
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| subset & (1 << i) != 0)
+                    .map(|(_, joltage)| *joltage),
+            );
+            chain.push(device);
+
+            chain
+                .windows(2)
+                .all(|pair| (1..=3).contains(&(pair[1] - pair[0])))
+        })
+        .count()
+        .into()
+}
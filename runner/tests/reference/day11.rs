@@ -0,0 +1,69 @@
+//! The waiting area simulated on a grid of characters until nobody moves.
+
+use aoc::Answer;
+
+fn occupied_after_settling(input: &str, tolerance: usize, sight: bool) -> usize {
+    let mut seats: Vec<Vec<char>> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim().chars().collect())
+        .collect();
+
+    loop {
+        let mut next = seats.clone();
+        for row in 0..seats.len() {
+            for column in 0..seats[row].len() {
+                let occupied = occupied_neighbours(&seats, row, column, sight);
+                next[row][column] = match seats[row][column] {
+                    'L' if occupied == 0 => '#',
+                    '#' if occupied >= tolerance => 'L',
+                    seat => seat,
+                };
+            }
+        }
+
+        if next == seats {
+            return seats.iter().flatten().filter(|seat| **seat == '#').count();
+        }
+        seats = next;
+    }
+}
+
+fn occupied_neighbours(seats: &[Vec<char>], row: usize, column: usize, sight: bool) -> usize {
+    let mut occupied = 0;
+
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if (dy, dx) == (0, 0) {
+                continue;
+            }
+
+            let (mut y, mut x) = (row as i64, column as i64);
+            loop {
+                y += dy;
+                x += dx;
+                if y < 0 || x < 0 || y >= seats.len() as i64 || x >= seats[0].len() as i64 {
+                    break;
+                }
+                match seats[y as usize][x as usize] {
+                    '#' => {
+                        occupied += 1;
+                        break;
+                    }
+                    '.' if sight => {}
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    occupied
+}
+
+pub fn part1(input: &str) -> Answer {
+    occupied_after_settling(input, 4, false).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    occupied_after_settling(input, 5, true).into()
+}
@@ -0,0 +1,82 @@
+//! The ship moved one unit at a time, turned one quarter at a time.
+
+use aoc::Answer;
+
+fn instructions(input: &str) -> Vec<(char, i64)> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let line = line.trim();
+            (line.chars().next().unwrap(), line[1..].parse().unwrap())
+        })
+        .collect()
+}
+
+fn direction(action: char) -> (i64, i64) {
+    match action {
+        'N' => (0, 1),
+        'S' => (0, -1),
+        'E' => (1, 0),
+        _ => (-1, 0),
+    }
+}
+
+/// `(x, y)` turned a quarter counterclockwise `quarters` times.
+fn turn_left((mut x, mut y): (i64, i64), quarters: i64) -> (i64, i64) {
+    for _ in 0..quarters.rem_euclid(4) {
+        let turned = (-y, x);
+        x = turned.0;
+        y = turned.1;
+    }
+    (x, y)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (mut x, mut y) = (0, 0);
+    let mut facing = (1, 0);
+
+    for (action, value) in instructions(input) {
+        match action {
+            'L' => facing = turn_left(facing, value / 90),
+            'R' => facing = turn_left(facing, -value / 90),
+            _ => {
+                let (dx, dy) = if action == 'F' {
+                    facing
+                } else {
+                    direction(action)
+                };
+                for _ in 0..value {
+                    x += dx;
+                    y += dy;
+                }
+            }
+        }
+    }
+
+    (x.abs() + y.abs()).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (mut x, mut y) = (0, 0);
+    let mut waypoint = (10, 1);
+
+    for (action, value) in instructions(input) {
+        match action {
+            'L' => waypoint = turn_left(waypoint, value / 90),
+            'R' => waypoint = turn_left(waypoint, -value / 90),
+            'F' => {
+                for _ in 0..value {
+                    x += waypoint.0;
+                    y += waypoint.1;
+                }
+            }
+            _ => {
+                let (dx, dy) = direction(action);
+                waypoint = (waypoint.0 + dx * value, waypoint.1 + dy * value);
+            }
+        }
+    }
+
+    (x.abs() + y.abs()).into()
+}
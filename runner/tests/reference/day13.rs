@@ -0,0 +1,48 @@
+//! Every minute tried in turn, for part 2 one bus period of the first bus at a
+//! time.
+
+use aoc::Answer;
+
+fn notes(input: &str) -> (i64, Vec<Option<i64>>) {
+    let mut lines = input.lines().filter(|line| !line.trim().is_empty());
+    let timestamp = lines.next().unwrap().trim().parse().unwrap();
+    let buses = lines
+        .next()
+        .unwrap()
+        .trim()
+        .split(',')
+        .map(|bus| bus.parse().ok())
+        .collect();
+
+    (timestamp, buses)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (timestamp, buses) = notes(input);
+
+    for minute in timestamp.. {
+        if let Some(bus) = buses.iter().flatten().find(|bus| minute % *bus == 0) {
+            return (bus * (minute - timestamp)).into();
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (_, buses) = notes(input);
+    let first = buses[0].unwrap();
+
+    // The earliest timestamp after 0, which would otherwise fit when every
+    // offset is a multiple of its bus id
+    (first..)
+        .step_by(first as usize)
+        .find(|minute| {
+            buses.iter().enumerate().all(|(offset, bus)| match bus {
+                Some(bus) => (minute + offset as i64) % bus == 0,
+                None => true,
+            })
+        })
+        .unwrap()
+        .into()
+}
@@ -0,0 +1,91 @@
+//! Masks applied bit by bit, and every floating address written one by one.
+
+use std::collections::HashMap;
+
+use aoc::Answer;
+
+enum Instruction {
+    Mask(Vec<char>),
+    Write(u64, u64),
+}
+
+fn program(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (target, value) = line.trim().split_once(" = ").unwrap();
+            if target == "mask" {
+                Instruction::Mask(value.chars().collect())
+            } else {
+                let address = target
+                    .trim_start_matches("mem[")
+                    .trim_end_matches(']')
+                    .parse()
+                    .unwrap();
+                Instruction::Write(address, value.parse().unwrap())
+            }
+        })
+        .collect()
+}
+
+/// Bit `i` of the mask, counting from the least significant one.
+fn mask_bit(mask: &[char], i: usize) -> char {
+    mask[35 - i]
+}
+
+pub fn part1(input: &str) -> Answer {
+    let mut memory = HashMap::new();
+    let mut mask = vec!['X'; 36];
+
+    for instruction in program(input) {
+        match instruction {
+            Instruction::Mask(new_mask) => mask = new_mask,
+            Instruction::Write(address, mut value) => {
+                for i in 0..36 {
+                    match mask_bit(&mask, i) {
+                        '1' => value |= 1 << i,
+                        '0' => value &= !(1 << i),
+                        _ => {}
+                    }
+                }
+                memory.insert(address, value);
+            }
+        }
+    }
+
+    memory.values().sum::<u64>().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut memory = HashMap::new();
+    let mut mask = vec!['0'; 36];
+
+    for instruction in program(input) {
+        match instruction {
+            Instruction::Mask(new_mask) => mask = new_mask,
+            Instruction::Write(address, value) => {
+                let floating: Vec<usize> = (0..36).filter(|i| mask_bit(&mask, *i) == 'X').collect();
+
+                for combination in 0..1u64 << floating.len() {
+                    let mut address = address;
+                    for i in 0..36 {
+                        if mask_bit(&mask, i) == '1' {
+                            address |= 1 << i;
+                        }
+                    }
+                    for (k, i) in floating.iter().enumerate() {
+                        if combination & (1 << k) != 0 {
+                            address |= 1 << i;
+                        } else {
+                            address &= !(1 << i);
+                        }
+                    }
+                    memory.insert(address, value);
+                }
+            }
+        }
+    }
+
+    memory.values().sum::<u64>().into()
+}
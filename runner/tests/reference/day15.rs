@@ -0,0 +1,22 @@
+//! The whole game kept in a list, searched backwards every turn.
+
+use aoc::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mut spoken: Vec<usize> = input
+        .trim()
+        .split(',')
+        .map(|number| number.parse().unwrap())
+        .collect();
+
+    while spoken.len() < 2020 {
+        let (last, before) = spoken.split_last().unwrap();
+        let next = match before.iter().rposition(|number| number == last) {
+            Some(turn) => before.len() - turn,
+            None => 0,
+        };
+        spoken.push(next);
+    }
+
+    spoken[2019].into()
+}
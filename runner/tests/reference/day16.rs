@@ -0,0 +1,112 @@
+//! Every assignment of fields to positions tried against every valid ticket.
+
+use aoc::Answer;
+
+struct Rule {
+    name: String,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl Rule {
+    fn allows(&self, value: u64) -> bool {
+        self.ranges
+            .iter()
+            .any(|(low, high)| (*low..=*high).contains(&value))
+    }
+}
+
+fn notes(input: &str) -> (Vec<Rule>, Vec<u64>, Vec<Vec<u64>>) {
+    let blocks = super::blocks(input);
+    let ticket = |line: &str| -> Vec<u64> {
+        line.split(',')
+            .map(|value| value.parse().unwrap())
+            .collect()
+    };
+
+    let rules = blocks[0]
+        .iter()
+        .map(|line| {
+            let (name, ranges) = line.split_once(": ").unwrap();
+            let ranges = ranges
+                .split(" or ")
+                .map(|range| {
+                    let (low, high) = range.split_once('-').unwrap();
+                    (low.parse().unwrap(), high.parse().unwrap())
+                })
+                .collect();
+            Rule {
+                name: name.into(),
+                ranges,
+            }
+        })
+        .collect();
+
+    let yours = ticket(blocks[1][1]);
+    let nearby = blocks[2][1..].iter().map(|line| ticket(line)).collect();
+
+    (rules, yours, nearby)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (rules, _, nearby) = notes(input);
+
+    nearby
+        .iter()
+        .flatten()
+        .filter(|value| !rules.iter().any(|rule| rule.allows(**value)))
+        .sum::<u64>()
+        .into()
+}
+
+/// Extends `assignment`, the rule for each position so far, into every
+/// complete assignment that fits `tickets`.
+fn assignments(
+    rules: &[Rule],
+    tickets: &[&Vec<u64>],
+    assignment: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    let position = assignment.len();
+    if position == rules.len() {
+        found.push(assignment.clone());
+        return;
+    }
+
+    for rule in 0..rules.len() {
+        if !assignment.contains(&rule)
+            && tickets
+                .iter()
+                .all(|ticket| rules[rule].allows(ticket[position]))
+        {
+            assignment.push(rule);
+            assignments(rules, tickets, assignment, found);
+            assignment.pop();
+        }
+    }
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (rules, yours, nearby) = notes(input);
+    let valid: Vec<&Vec<u64>> = nearby
+        .iter()
+        .filter(|ticket| {
+            ticket
+                .iter()
+                .all(|value| rules.iter().any(|rule| rule.allows(*value)))
+        })
+        .collect();
+
+    let mut found = vec![];
+    assignments(&rules, &valid, &mut vec![], &mut found);
+    if found.len() != 1 {
+        return Answer::None;
+    }
+
+    found[0]
+        .iter()
+        .zip(&yours)
+        .filter(|(rule, _)| rules[**rule].name.starts_with("departure"))
+        .map(|(_, value)| value)
+        .product::<u64>()
+        .into()
+}
@@ -0,0 +1,67 @@
+//! Active cubes kept in a set, in as many dimensions as asked for.
+
+use std::collections::{HashMap, HashSet};
+
+use aoc::Answer;
+
+type Cube = Vec<i64>;
+
+fn neighbours(cube: &Cube) -> Vec<Cube> {
+    let mut neighbours: Vec<Cube> = vec![vec![]];
+    for coordinate in cube {
+        neighbours = neighbours
+            .iter()
+            .flat_map(|prefix| {
+                (-1..=1).map(move |delta| {
+                    let mut neighbour = prefix.clone();
+                    neighbour.push(coordinate + delta);
+                    neighbour
+                })
+            })
+            .collect();
+    }
+    neighbours.retain(|neighbour| neighbour != cube);
+    neighbours
+}
+
+fn active_after_boot(input: &str, dimensions: usize) -> usize {
+    let mut active: HashSet<Cube> = HashSet::new();
+    for (y, line) in input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+    {
+        for (x, c) in line.trim().chars().enumerate() {
+            if c == '#' {
+                let mut cube = vec![x as i64, y as i64];
+                cube.resize(dimensions, 0);
+                active.insert(cube);
+            }
+        }
+    }
+
+    for _ in 0..6 {
+        let mut counts: HashMap<Cube, usize> = HashMap::new();
+        for cube in &active {
+            for neighbour in neighbours(cube) {
+                *counts.entry(neighbour).or_default() += 1;
+            }
+        }
+
+        active = counts
+            .into_iter()
+            .filter(|(cube, count)| *count == 3 || (*count == 2 && active.contains(cube)))
+            .map(|(cube, _)| cube)
+            .collect();
+    }
+
+    active.len()
+}
+
+pub fn part1(input: &str) -> Answer {
+    active_after_boot(input, 3).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    active_after_boot(input, 4).into()
+}
@@ -0,0 +1,73 @@
+//! Expressions evaluated by precedence climbing, with each part's precedences.
+
+use aoc::Answer;
+
+struct Parser {
+    tokens: Vec<char>,
+    position: usize,
+    /// Precedence of `+` and of `*`.
+    precedence: (u8, u8),
+}
+
+impl Parser {
+    fn operand(&mut self) -> i64 {
+        let token = self.tokens[self.position];
+        self.position += 1;
+
+        if token == '(' {
+            let value = self.expression(0);
+            self.position += 1;
+            value
+        } else {
+            token.to_digit(10).unwrap() as i64
+        }
+    }
+
+    fn expression(&mut self, min_precedence: u8) -> i64 {
+        let mut value = self.operand();
+
+        while let Some(operator) = self.tokens.get(self.position).copied() {
+            let precedence = match operator {
+                '+' => self.precedence.0,
+                '*' => self.precedence.1,
+                _ => break,
+            };
+            if precedence < min_precedence {
+                break;
+            }
+            self.position += 1;
+
+            let right = self.expression(precedence + 1);
+            value = if operator == '+' {
+                value + right
+            } else {
+                value * right
+            };
+        }
+
+        value
+    }
+}
+
+fn sum(input: &str, precedence: (u8, u8)) -> i64 {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            Parser {
+                tokens: line.chars().filter(|c| !c.is_whitespace()).collect(),
+                position: 0,
+                precedence,
+            }
+            .expression(0)
+        })
+        .sum()
+}
+
+pub fn part1(input: &str) -> Answer {
+    sum(input, (1, 1)).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    sum(input, (2, 1)).into()
+}
@@ -0,0 +1,97 @@
+//! Rules matched by following every alternative, keeping all the places a rule
+//! can end. That handles the loops of part 2 as they are, without unrolling
+//! them.
+
+use std::collections::HashMap;
+
+use aoc::Answer;
+
+enum Rule {
+    Letter(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+fn notes(input: &str) -> (HashMap<usize, Rule>, Vec<Vec<char>>) {
+    let blocks = super::blocks(input);
+
+    let rules = blocks[0]
+        .iter()
+        .map(|line| {
+            let (number, body) = line.split_once(": ").unwrap();
+            let rule = if body.starts_with('"') {
+                Rule::Letter(body.chars().nth(1).unwrap())
+            } else {
+                Rule::Alternatives(
+                    body.split(" | ")
+                        .map(|sequence| {
+                            sequence
+                                .split(' ')
+                                .map(|rule| rule.parse().unwrap())
+                                .collect()
+                        })
+                        .collect(),
+                )
+            };
+            (number.parse().unwrap(), rule)
+        })
+        .collect();
+    let messages = blocks
+        .get(1)
+        .map(|block| block.iter().map(|line| line.chars().collect()).collect())
+        .unwrap_or_default();
+
+    (rules, messages)
+}
+
+/// Every position where a match of `rule` starting at `start` can end.
+fn ends(rules: &HashMap<usize, Rule>, rule: usize, message: &[char], start: usize) -> Vec<usize> {
+    match &rules[&rule] {
+        Rule::Letter(letter) => {
+            if message.get(start) == Some(letter) {
+                vec![start + 1]
+            } else {
+                vec![]
+            }
+        }
+        Rule::Alternatives(alternatives) => alternatives
+            .iter()
+            .flat_map(|sequence| {
+                sequence.iter().fold(vec![start], |positions, rule| {
+                    let mut next: Vec<usize> = positions
+                        .iter()
+                        .flat_map(|position| ends(rules, *rule, message, *position))
+                        .collect();
+                    next.sort_unstable();
+                    next.dedup();
+                    next
+                })
+            })
+            .collect(),
+    }
+}
+
+fn count_matches(rules: &HashMap<usize, Rule>, messages: &[Vec<char>]) -> usize {
+    messages
+        .iter()
+        .filter(|message| ends(rules, 0, message, 0).contains(&message.len()))
+        .count()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (rules, messages) = notes(input);
+    count_matches(&rules, &messages).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (mut rules, messages) = notes(input);
+    if [8, 11, 42, 31]
+        .iter()
+        .any(|index| !rules.contains_key(index))
+    {
+        return Answer::None;
+    }
+    rules.insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+    rules.insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+
+    count_matches(&rules, &messages).into()
+}
@@ -0,0 +1,163 @@
+//! Tiles placed by backtracking over every tile in every orientation, instead
+//! of trusting that each border matches a single other tile.
+
+use std::collections::HashSet;
+
+use aoc::Answer;
+
+type Pixels = Vec<Vec<bool>>;
+
+const SEA_MONSTER: [&str; 3] = [
+    "..................#.",
+    "#....##....##....###",
+    ".#..#..#..#..#..#...",
+];
+
+fn tiles(input: &str) -> Vec<(u64, Pixels)> {
+    super::blocks(input)
+        .iter()
+        .map(|lines| {
+            let id = lines[0]
+                .trim_start_matches("Tile ")
+                .trim_end_matches(':')
+                .parse()
+                .unwrap();
+            let pixels = lines[1..]
+                .iter()
+                .map(|line| line.chars().map(|c| c == '#').collect())
+                .collect();
+            (id, pixels)
+        })
+        .collect()
+}
+
+/// Quarter turn clockwise.
+fn rotate(pixels: &Pixels) -> Pixels {
+    let side = pixels.len();
+    (0..side)
+        .map(|row| {
+            (0..side)
+                .map(|column| pixels[side - 1 - column][row])
+                .collect()
+        })
+        .collect()
+}
+
+/// All 8 ways to turn and flip `pixels`.
+fn orientations(pixels: &Pixels) -> Vec<Pixels> {
+    let mut orientations = vec![];
+    let mut pixels = pixels.clone();
+    for _ in 0..4 {
+        let mut flipped = pixels.clone();
+        flipped.reverse();
+        orientations.push(flipped);
+        pixels = rotate(&pixels);
+        orientations.push(pixels.clone());
+    }
+    orientations
+}
+
+/// Fills `placed` in row major order with tiles fitting their left and top
+/// neighbours, `false` if there's no way to.
+fn place(tiles: &[(u64, Vec<Pixels>)], side: usize, placed: &mut Vec<(usize, Pixels)>) -> bool {
+    let position = placed.len();
+    if position == tiles.len() {
+        return true;
+    }
+
+    for (i, (_, orientations)) in tiles.iter().enumerate() {
+        if placed.iter().any(|(used, _)| *used == i) {
+            continue;
+        }
+
+        for pixels in orientations {
+            let fits_left = position.is_multiple_of(side) || {
+                let left = &placed[position - 1].1;
+                (0..10).all(|row| left[row][9] == pixels[row][0])
+            };
+            let fits_top = position < side || placed[position - side].1[9] == pixels[0];
+
+            if fits_left && fits_top {
+                placed.push((i, pixels.clone()));
+                if place(tiles, side, placed) {
+                    return true;
+                }
+                placed.pop();
+            }
+        }
+    }
+
+    false
+}
+
+/// The tiles arranged into a square, by index into `tiles`.
+fn arrange(tiles: &[(u64, Pixels)]) -> (usize, Vec<(usize, Pixels)>) {
+    let side = (1..).find(|side| side * side >= tiles.len()).unwrap();
+    let tiles: Vec<(u64, Vec<Pixels>)> = tiles
+        .iter()
+        .map(|(id, pixels)| (*id, orientations(pixels)))
+        .collect();
+
+    let mut placed = vec![];
+    assert!(place(&tiles, side, &mut placed), "The tiles don't fit");
+    (side, placed)
+}
+
+pub fn part1(input: &str) -> Answer {
+    let tiles = tiles(input);
+    let (side, placed) = arrange(&tiles);
+
+    [0, side - 1, side * (side - 1), side * side - 1]
+        .iter()
+        .map(|corner| tiles[placed[*corner].0].0)
+        .product::<u64>()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let tiles = tiles(input);
+    let (side, placed) = arrange(&tiles);
+
+    let mut image: Pixels = vec![vec![false; 8 * side]; 8 * side];
+    for (position, (_, pixels)) in placed.iter().enumerate() {
+        for row in 0..8 {
+            for column in 0..8 {
+                image[position / side * 8 + row][position % side * 8 + column] =
+                    pixels[row + 1][column + 1];
+            }
+        }
+    }
+
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(column, _)| (row, column))
+        })
+        .collect();
+
+    // Noise sometimes makes up a sea monster in another orientation than the
+    // one the image is meant to be seen in, with all the others
+    let rough = image.iter().flatten().filter(|pixel| **pixel).count();
+    let in_monsters = orientations(&image)
+        .iter()
+        .map(|image| {
+            let mut in_monster = HashSet::new();
+            for row in 0..=image.len() - SEA_MONSTER.len() {
+                for column in 0..=image.len() - SEA_MONSTER[0].len() {
+                    if monster.iter().all(|(r, c)| image[row + r][column + c]) {
+                        for (r, c) in &monster {
+                            in_monster.insert((row + r, column + c));
+                        }
+                    }
+                }
+            }
+            in_monster.len()
+        })
+        .max()
+        .unwrap();
+
+    (rough - in_monsters).into()
+}
@@ -0,0 +1,92 @@
+//! Every way to give each allergen its own ingredient that's in all the foods
+//! listing it, instead of narrowing down candidates greedily.
+
+use std::collections::BTreeSet;
+
+use aoc::Answer;
+
+type Food<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+fn foods(input: &str) -> Vec<Food<'_>> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (ingredients, allergens) = line.trim().split_once(" (contains ").unwrap();
+            (
+                ingredients.split(' ').collect(),
+                allergens.trim_end_matches(')').split(", ").collect(),
+            )
+        })
+        .collect()
+}
+
+/// Every assignment of an ingredient to each of `allergens`, in the same order,
+/// that `foods` allow.
+fn assignments<'a>(foods: &[Food<'a>], allergens: &[&'a str]) -> Vec<Vec<&'a str>> {
+    let ingredients: BTreeSet<&str> = foods.iter().flat_map(|(food, _)| food.clone()).collect();
+    let mut found = vec![];
+    extend(foods, allergens, &ingredients, &mut vec![], &mut found);
+    found
+}
+
+fn extend<'a>(
+    foods: &[Food<'a>],
+    allergens: &[&'a str],
+    ingredients: &BTreeSet<&'a str>,
+    assignment: &mut Vec<&'a str>,
+    found: &mut Vec<Vec<&'a str>>,
+) {
+    let allergen = match allergens.get(assignment.len()) {
+        Some(allergen) => allergen,
+        None => {
+            found.push(assignment.clone());
+            return;
+        }
+    };
+
+    for ingredient in ingredients {
+        let possible = !assignment.contains(ingredient)
+            && foods
+                .iter()
+                .filter(|(_, listed)| listed.contains(allergen))
+                .all(|(food, _)| food.contains(ingredient));
+
+        if possible {
+            assignment.push(ingredient);
+            extend(foods, allergens, ingredients, assignment, found);
+            assignment.pop();
+        }
+    }
+}
+
+fn allergens<'a>(foods: &[Food<'a>]) -> Vec<&'a str> {
+    let allergens: BTreeSet<&str> = foods
+        .iter()
+        .flat_map(|(_, listed)| listed.clone())
+        .collect();
+    allergens.into_iter().collect()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let foods = foods(input);
+    let possible: BTreeSet<&str> = assignments(&foods, &allergens(&foods))
+        .into_iter()
+        .flatten()
+        .collect();
+
+    foods
+        .iter()
+        .flat_map(|(food, _)| food)
+        .filter(|ingredient| !possible.contains(*ingredient))
+        .count()
+        .into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let foods = foods(input);
+    match assignments(&foods, &allergens(&foods)).as_slice() {
+        [assignment] => assignment.join(",").into(),
+        _ => Answer::None,
+    }
+}
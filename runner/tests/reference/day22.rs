@@ -0,0 +1,82 @@
+//! Both games played card by card, remembering every state of a recursive game.
+
+use std::collections::{HashSet, VecDeque};
+
+use aoc::Answer;
+
+type Deck = VecDeque<usize>;
+
+fn decks(input: &str) -> (Deck, Deck) {
+    let blocks = super::blocks(input);
+    let deck = |block: &Vec<&str>| {
+        block
+            .iter()
+            .filter(|line| !line.starts_with("Player"))
+            .map(|line| line.parse().unwrap())
+            .collect()
+    };
+
+    (deck(&blocks[0]), deck(&blocks[1]))
+}
+
+fn score(deck: &Deck) -> usize {
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, card)| (i + 1) * card)
+        .sum()
+}
+
+pub fn part1(input: &str) -> Answer {
+    let (mut one, mut two) = decks(input);
+
+    while !one.is_empty() && !two.is_empty() {
+        let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+        if a > b {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+
+    score(if one.is_empty() { &two } else { &one }).into()
+}
+
+/// Plays a game of recursive combat, `true` if player 1 wins it.
+fn recursive_combat(one: &mut Deck, two: &mut Deck) -> bool {
+    let mut seen = HashSet::new();
+
+    while !one.is_empty() && !two.is_empty() {
+        if !seen.insert((one.clone(), two.clone())) {
+            return true;
+        }
+
+        let (a, b) = (one.pop_front().unwrap(), two.pop_front().unwrap());
+        let one_wins = if one.len() >= a && two.len() >= b {
+            recursive_combat(
+                &mut one.iter().take(a).copied().collect(),
+                &mut two.iter().take(b).copied().collect(),
+            )
+        } else {
+            a > b
+        };
+
+        if one_wins {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+
+    two.is_empty()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (mut one, mut two) = decks(input);
+
+    if recursive_combat(&mut one, &mut two) {
+        score(&one).into()
+    } else {
+        score(&two).into()
+    }
+}
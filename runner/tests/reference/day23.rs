@@ -0,0 +1,41 @@
+//! Cups moved around in a plain list, always rotated so the current cup is first.
+
+use aoc::Answer;
+
+pub fn part1(input: &str) -> Answer {
+    let mut cups: Vec<u32> = input
+        .trim()
+        .chars()
+        .map(|c| c.to_digit(10).unwrap())
+        .collect();
+    let highest = *cups.iter().max().unwrap();
+
+    for _ in 0..100 {
+        let current = cups[0];
+        let picked: Vec<u32> = cups.drain(1..4).collect();
+
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                highest
+            } else {
+                destination - 1
+            };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+
+        let at = cups.iter().position(|cup| *cup == destination).unwrap();
+        cups.splice(at + 1..at + 1, picked);
+        cups.rotate_left(1);
+    }
+
+    let one = cups.iter().position(|cup| *cup == 1).unwrap();
+    cups.rotate_left(one);
+    cups[1..]
+        .iter()
+        .map(|cup| cup.to_string())
+        .collect::<String>()
+        .into()
+}
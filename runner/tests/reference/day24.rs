@@ -0,0 +1,77 @@
+//! Tiles on axial coordinates, with every black tile and its neighbours looked
+//! at each day.
+
+use std::collections::HashSet;
+
+use aoc::Answer;
+
+type Tile = (i64, i64);
+
+const DIRECTIONS: [(&str, Tile); 6] = [
+    ("e", (1, 0)),
+    ("w", (-1, 0)),
+    ("ne", (1, -1)),
+    ("nw", (0, -1)),
+    ("se", (0, 1)),
+    ("sw", (-1, 1)),
+];
+
+fn black_tiles(input: &str) -> HashSet<Tile> {
+    let mut black = HashSet::new();
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let mut steps = line.trim();
+        let mut tile = (0, 0);
+        while !steps.is_empty() {
+            // Two letter directions first, so `se` isn't read as `s` and `e`
+            let (name, (dq, dr)) = DIRECTIONS
+                .iter()
+                .rev()
+                .find(|(name, _)| steps.starts_with(name))
+                .unwrap();
+            tile = (tile.0 + dq, tile.1 + dr);
+            steps = &steps[name.len()..];
+        }
+
+        if !black.insert(tile) {
+            black.remove(&tile);
+        }
+    }
+
+    black
+}
+
+fn neighbours((q, r): Tile) -> impl Iterator<Item = Tile> {
+    DIRECTIONS.iter().map(move |(_, (dq, dr))| (q + dq, r + dr))
+}
+
+pub fn part1(input: &str) -> Answer {
+    black_tiles(input).len().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut black = black_tiles(input);
+
+    for _ in 0..100 {
+        let candidates: HashSet<Tile> = black
+            .iter()
+            .flat_map(|tile| neighbours(*tile).chain(std::iter::once(*tile)))
+            .collect();
+
+        black = candidates
+            .into_iter()
+            .filter(|tile| {
+                let count = neighbours(*tile)
+                    .filter(|neighbour| black.contains(neighbour))
+                    .count();
+                if black.contains(tile) {
+                    count == 1 || count == 2
+                } else {
+                    count == 2
+                }
+            })
+            .collect();
+    }
+
+    black.len().into()
+}
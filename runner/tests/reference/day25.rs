@@ -0,0 +1,23 @@
+//! Loop sizes found by running the transformation one loop at a time.
+
+use aoc::Answer;
+
+const MODULUS: i64 = 20201227;
+
+pub fn part1(input: &str) -> Answer {
+    let keys = super::numbers(input);
+
+    let mut loop_size = 0;
+    let mut value = 1;
+    while value != keys[0] {
+        value = value * 7 % MODULUS;
+        loop_size += 1;
+    }
+
+    let mut encryption_key = 1;
+    for _ in 0..loop_size {
+        encryption_key = encryption_key * keys[1] % MODULUS;
+    }
+
+    encryption_key.into()
+}
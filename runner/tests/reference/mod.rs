@@ -0,0 +1,54 @@
+//! Slow but obviously correct solutions for every day, written straight from
+//! the puzzle statements: they parse the raw text themselves and try every
+//! possibility where the real solutions take a shortcut. Part 2 of days 15 and
+//! 23 is missing, running the game to the end is all there is to it and takes
+//! too long done naively, and day 25 has no part 2.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// The blocks of lines between blank lines.
+fn blocks(input: &str) -> Vec<Vec<&str>> {
+    let mut blocks = vec![vec![]];
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            blocks.push(vec![]);
+        } else {
+            blocks.last_mut().unwrap().push(line.trim());
+        }
+    }
+    blocks.retain(|block| !block.is_empty());
+    blocks
+}
+
+/// One number per non-empty line.
+fn numbers(input: &str) -> Vec<i64> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.trim().parse().unwrap())
+        .collect()
+}